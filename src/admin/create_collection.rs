@@ -0,0 +1,24 @@
+use crate::{data_sources::DataSource, ejson::EJSON};
+use mongodb::options::CreateCollectionOptions;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateCollectionBody {
+    db: String,
+    collection: String,
+    options: Option<CreateCollectionOptions>,
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    EJSON(args): EJSON<CreateCollectionBody>,
+) -> Result<EJSON<()>, EJSON<mongodb::error::Error>> {
+    client
+        .database(&args.db)
+        .create_collection(&args.collection)
+        .with_options(args.options)
+        .await
+        .map_err(EJSON)?;
+
+    Ok(EJSON(()))
+}
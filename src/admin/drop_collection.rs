@@ -0,0 +1,25 @@
+use crate::{data_sources::DataSource, ejson::EJSON};
+use mongodb::{bson::Document, options::DropCollectionOptions};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct DropCollectionBody {
+    db: String,
+    collection: String,
+    options: Option<DropCollectionOptions>,
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    EJSON(args): EJSON<DropCollectionBody>,
+) -> Result<EJSON<()>, EJSON<mongodb::error::Error>> {
+    client
+        .database(&args.db)
+        .collection::<Document>(&args.collection)
+        .drop()
+        .with_options(args.options)
+        .await
+        .map_err(EJSON)?;
+
+    Ok(EJSON(()))
+}
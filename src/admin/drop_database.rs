@@ -0,0 +1,23 @@
+use crate::{data_sources::DataSource, ejson::EJSON};
+use mongodb::options::DropDatabaseOptions;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct DropDatabaseBody {
+    db: String,
+    options: Option<DropDatabaseOptions>,
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    EJSON(args): EJSON<DropDatabaseBody>,
+) -> Result<EJSON<()>, EJSON<mongodb::error::Error>> {
+    client
+        .database(&args.db)
+        .drop()
+        .with_options(args.options)
+        .await
+        .map_err(EJSON)?;
+
+    Ok(EJSON(()))
+}
@@ -0,0 +1,46 @@
+use crate::{data_sources::DataSource, ejson::EJSON};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use futures::stream::TryStreamExt;
+use mongodb::bson::doc;
+use serde::Deserialize;
+use serde_json::json;
+
+use super::results::ValidationRules;
+
+#[derive(Debug, Deserialize)]
+pub struct GetValidatorBody {
+    db: String,
+    collection: String,
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    EJSON(args): EJSON<GetValidatorBody>,
+) -> Result<EJSON<ValidationRules>, Response> {
+    let specification = client
+        .database(&args.db)
+        .list_collections()
+        .filter(doc! {"name": &args.collection})
+        .await
+        .map_err(|error| EJSON(error).into_response())?
+        .try_next()
+        .await
+        .map_err(|error| EJSON(error).into_response())?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(json!({"message": format!("Collection not found: {}", args.collection)})),
+            )
+                .into_response()
+        })?;
+
+    Ok(EJSON(ValidationRules {
+        validator: specification.options.validator,
+        validation_level: specification.options.validation_level,
+        validation_action: specification.options.validation_action,
+    }))
+}
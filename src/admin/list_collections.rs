@@ -0,0 +1,53 @@
+use crate::{data_sources::DataSource, ejson::EJSON};
+use futures::stream::TryStreamExt;
+use mongodb::{
+    bson::{self, Bson, Document},
+    options::ListCollectionsOptions,
+    results::CollectionSpecification,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListCollectionsBody {
+    db: String,
+    filter: Option<Document>,
+    name_only: Option<bool>,
+    options: Option<ListCollectionsOptions>,
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    EJSON(args): EJSON<ListCollectionsBody>,
+) -> Result<EJSON<Vec<Bson>>, EJSON<mongodb::error::Error>> {
+    let database = client.database(&args.db);
+    let mut options = args.options.unwrap_or_default();
+
+    options.filter = args.filter.or(options.filter);
+
+    if args.name_only.unwrap_or(false) {
+        let names = database
+            .list_collection_names()
+            .with_options(options)
+            .await
+            .map_err(EJSON)?;
+
+        return Ok(EJSON(names.into_iter().map(Bson::from).collect()));
+    }
+
+    let cursor = database
+        .list_collections()
+        .with_options(options)
+        .await
+        .map_err(EJSON)?;
+
+    let specifications: Vec<CollectionSpecification> = cursor.try_collect().await.map_err(EJSON)?;
+
+    let result = specifications
+        .iter()
+        .map(bson::to_bson)
+        .collect::<Result<Vec<Bson>, _>>()
+        .map_err(|error| EJSON(error.into()))?;
+
+    Ok(EJSON(result))
+}
@@ -0,0 +1,47 @@
+use crate::{data_sources::DataSource, ejson::EJSON};
+use mongodb::{
+    bson::{self, Bson, Document},
+    options::ListDatabasesOptions,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListDatabasesBody {
+    filter: Option<Document>,
+    name_only: Option<bool>,
+    options: Option<ListDatabasesOptions>,
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    EJSON(args): EJSON<ListDatabasesBody>,
+) -> Result<EJSON<Vec<Bson>>, EJSON<mongodb::error::Error>> {
+    let mut options = args.options.unwrap_or_default();
+
+    options.filter = args.filter.or(options.filter);
+
+    if args.name_only.unwrap_or(false) {
+        let names = client
+            .list_database_names()
+            .with_options(options)
+            .await
+            .map_err(EJSON)?;
+
+        return Ok(EJSON(names.into_iter().map(Bson::from).collect()));
+    }
+
+    let specifications = client
+        .list_databases()
+        .with_options(options)
+        .await
+        .map_err(EJSON)?;
+
+    let result = specifications
+        .iter()
+        .map(bson::to_bson)
+        .collect::<Result<Vec<Bson>, _>>()
+        .map_err(|error| EJSON(error.into()))?;
+
+    Ok(EJSON(result))
+}
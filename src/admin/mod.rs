@@ -0,0 +1,10 @@
+pub mod create_collection;
+pub mod drop_collection;
+pub mod drop_database;
+pub mod get_validator;
+pub mod list_collections;
+pub mod list_databases;
+pub mod rename_collection;
+pub mod results;
+pub mod run_command;
+pub mod set_validator;
@@ -0,0 +1,53 @@
+use crate::{data_sources::DataSource, ejson::EJSON};
+use mongodb::{
+    bson::{self, doc, Bson},
+    options::WriteConcern,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct RenameCollectionBody {
+    db: String,
+    collection: String,
+    to: String,
+    options: Option<RenameCollectionOptions>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenameCollectionOptions {
+    drop_target: Option<bool>,
+    write_concern: Option<WriteConcern>,
+    comment: Option<Bson>,
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    EJSON(args): EJSON<RenameCollectionBody>,
+) -> Result<EJSON<()>, EJSON<mongodb::error::Error>> {
+    let options = args.options.unwrap_or_default();
+    let mut command = doc! {
+        "renameCollection": format!("{}.{}", args.db, args.collection),
+        "to": format!("{}.{}", args.db, args.to),
+        "dropTarget": options.drop_target.unwrap_or(false),
+    };
+
+    if let Some(write_concern) = options.write_concern {
+        command.insert(
+            "writeConcern",
+            bson::to_bson(&write_concern).map_err(|error| EJSON(error.into()))?,
+        );
+    }
+
+    if let Some(comment) = options.comment {
+        command.insert("comment", comment);
+    }
+
+    client
+        .database("admin")
+        .run_command(command)
+        .await
+        .map_err(EJSON)?;
+
+    Ok(EJSON(()))
+}
@@ -0,0 +1,13 @@
+use mongodb::{
+    bson::Document,
+    options::{ValidationAction, ValidationLevel},
+};
+use serde::Serialize;
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationRules {
+    pub validator: Option<Document>,
+    pub validation_level: Option<ValidationLevel>,
+    pub validation_action: Option<ValidationAction>,
+}
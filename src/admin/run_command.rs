@@ -0,0 +1,141 @@
+use crate::{data_sources::DataSource, ejson::EJSON};
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use mongodb::bson::Document;
+use serde::Deserialize;
+use serde_json::json;
+use std::{collections::HashSet, sync::Arc};
+
+pub const DEFAULT_ALLOWED_COMMANDS: [&str; 8] = [
+    "buildInfo",
+    "collMod",
+    "collStats",
+    "dataSize",
+    "dbStats",
+    "hello",
+    "ping",
+    "validate",
+];
+
+#[derive(Clone, Debug)]
+pub struct AllowedCommands(Arc<HashSet<String>>);
+
+impl AllowedCommands {
+    pub fn new<I, S>(commands: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let commands = commands
+            .into_iter()
+            .map(|command| command.as_ref().to_lowercase())
+            .collect();
+
+        Self(Arc::new(commands))
+    }
+
+    pub fn contains(&self, command: &str) -> bool {
+        self.0.contains(&command.to_lowercase())
+    }
+}
+
+impl Default for AllowedCommands {
+    fn default() -> Self {
+        Self::new(DEFAULT_ALLOWED_COMMANDS)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RunCommandBody {
+    db: String,
+    command: Document,
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    State(allowed_commands): State<AllowedCommands>,
+    EJSON(args): EJSON<RunCommandBody>,
+) -> Result<EJSON<Document>, Response> {
+    validate_command(&allowed_commands, &args.command)?;
+
+    let result = client
+        .database(&args.db)
+        .run_command(args.command)
+        .await
+        .map_err(|error| EJSON(error).into_response())?;
+
+    Ok(EJSON(result))
+}
+
+fn validate_command(
+    allowed_commands: &AllowedCommands,
+    command: &Document,
+) -> Result<(), Response> {
+    let Some(name) = command.keys().next() else {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(json!({"message": "Command document is empty"})),
+        )
+            .into_response());
+    };
+
+    if allowed_commands.contains(name) {
+        return Ok(());
+    }
+
+    Err((
+        StatusCode::FORBIDDEN,
+        Json(json!({"message": format!("Command not allowed: {name}")})),
+    )
+        .into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::to_bytes;
+    use mongodb::bson::doc;
+    use serde_json::Value;
+
+    async fn message(res: Response) -> (StatusCode, Value) {
+        let (parts, body) = res.into_parts();
+        let body_bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let body_json: Value = serde_json::from_slice(&body_bytes).unwrap();
+
+        (parts.status, body_json.get("message").unwrap().clone())
+    }
+
+    #[test]
+    fn validate_command_allowed() {
+        let allowed_commands = AllowedCommands::default();
+
+        assert!(validate_command(&allowed_commands, &doc! {"collStats": "users"}).is_ok());
+        assert!(validate_command(&allowed_commands, &doc! {"dbstats": 1}).is_ok());
+    }
+
+    #[tokio::test]
+    async fn validate_command_not_allowed() {
+        let allowed_commands = AllowedCommands::default();
+        let res = validate_command(&allowed_commands, &doc! {"dropDatabase": 1})
+            .err()
+            .unwrap();
+        let (status, message) = message(res).await;
+
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(message, "Command not allowed: dropDatabase");
+    }
+
+    #[tokio::test]
+    async fn validate_command_empty() {
+        let allowed_commands = AllowedCommands::new(["ping"]);
+        let res = validate_command(&allowed_commands, &doc! {}).err().unwrap();
+        let (status, message) = message(res).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(message, "Command document is empty");
+    }
+}
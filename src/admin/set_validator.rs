@@ -0,0 +1,70 @@
+use crate::{data_sources::DataSource, ejson::EJSON};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use mongodb::{
+    bson::{self, doc, Document},
+    options::{ValidationAction, ValidationLevel},
+};
+use serde::Deserialize;
+use serde_json::json;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetValidatorBody {
+    db: String,
+    collection: String,
+    validator: Option<Document>,
+    validation_level: Option<ValidationLevel>,
+    validation_action: Option<ValidationAction>,
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    EJSON(args): EJSON<SetValidatorBody>,
+) -> Result<EJSON<()>, Response> {
+    if args.validator.is_none()
+        && args.validation_level.is_none()
+        && args.validation_action.is_none()
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(json!({
+                "message": "At least one of validator, validationLevel or validationAction is required"
+            })),
+        )
+            .into_response());
+    }
+
+    let mut command = doc! {"collMod": &args.collection};
+
+    if let Some(validator) = args.validator {
+        command.insert("validator", validator);
+    }
+
+    if let Some(validation_level) = args.validation_level {
+        command.insert(
+            "validationLevel",
+            bson::to_bson(&validation_level)
+                .map_err(|error| EJSON(mongodb::error::Error::from(error)).into_response())?,
+        );
+    }
+
+    if let Some(validation_action) = args.validation_action {
+        command.insert(
+            "validationAction",
+            bson::to_bson(&validation_action)
+                .map_err(|error| EJSON(mongodb::error::Error::from(error)).into_response())?,
+        );
+    }
+
+    client
+        .database(&args.db)
+        .run_command(command)
+        .await
+        .map_err(|error| EJSON(error).into_response())?;
+
+    Ok(EJSON(()))
+}
@@ -0,0 +1,59 @@
+use std::{fmt, io};
+
+use crate::{config::ConfigError, server::tls::TlsError};
+
+#[derive(Debug)]
+pub enum StartupError {
+    Config(ConfigError),
+    Tls(TlsError),
+    Connect {
+        data_source: String,
+        source: mongodb::error::Error,
+    },
+    Bind {
+        listen: String,
+        source: io::Error,
+    },
+}
+
+impl From<ConfigError> for StartupError {
+    fn from(error: ConfigError) -> Self {
+        StartupError::Config(error)
+    }
+}
+
+impl From<TlsError> for StartupError {
+    fn from(error: TlsError) -> Self {
+        StartupError::Tls(error)
+    }
+}
+
+impl fmt::Display for StartupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StartupError::Config(error) => write!(f, "{error}"),
+            StartupError::Tls(error) => write!(f, "{error}"),
+            StartupError::Connect {
+                data_source,
+                source,
+            } => write!(
+                f,
+                "Failed to connect to data source {data_source}: {source}"
+            ),
+            StartupError::Bind { listen, source } => {
+                write!(f, "Failed to listen on {listen}: {source}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for StartupError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StartupError::Config(error) => Some(error),
+            StartupError::Tls(error) => Some(error),
+            StartupError::Connect { source, .. } => Some(source),
+            StartupError::Bind { source, .. } => Some(source),
+        }
+    }
+}
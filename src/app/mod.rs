@@ -1,18 +1,240 @@
-use axum::{routing::post, Router};
+mod error;
+pub mod reload;
 
-use crate::{crud, mdb};
+pub use error::StartupError;
 
-pub async fn build() -> Router {
-    let client = mdb::get_client().await;
+use axum::{
+    extract::{DefaultBodyLimit, FromRef},
+    middleware,
+    routing::{get, post},
+    Router,
+};
+use mongodb::Client;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, RwLock},
+    time::Duration,
+};
+use tokio_util::sync::CancellationToken;
+
+use crate::{
+    admin, admin::run_command::AllowedCommands, change_stream, config::Config, crud, cursors,
+    cursors::CursorRegistry, data_api, data_sources, data_sources::DataSources, gridfs, health,
+    indexes, rest,
+};
+
+#[derive(Clone)]
+pub struct AppState {
+    pub cursors: CursorRegistry,
+    runtime: Arc<RwLock<Arc<RuntimeState>>>,
+    retired: Arc<Mutex<Retired>>,
+    streams: CancellationToken,
+}
+
+#[derive(Default)]
+struct Retired {
+    next_id: u64,
+    clients: HashMap<u64, Client>,
+}
+
+/// The part of the state a config reload replaces. Requests take a snapshot
+/// when extracting, so a swap never changes settings under a running handler.
+pub struct RuntimeState {
+    pub data_sources: DataSources,
+    pub allowed_commands: AllowedCommands,
+}
+
+impl FromRef<AppState> for DataSources {
+    fn from_ref(state: &AppState) -> Self {
+        state.runtime().data_sources.clone()
+    }
+}
+
+impl FromRef<AppState> for CursorRegistry {
+    fn from_ref(state: &AppState) -> Self {
+        state.cursors.clone()
+    }
+}
+
+impl FromRef<AppState> for CancellationToken {
+    fn from_ref(state: &AppState) -> Self {
+        state.streams.clone()
+    }
+}
+
+impl FromRef<AppState> for AllowedCommands {
+    fn from_ref(state: &AppState) -> Self {
+        state.runtime().allowed_commands.clone()
+    }
+}
+
+impl AppState {
+    pub fn new(
+        data_sources: DataSources,
+        cursors: CursorRegistry,
+        allowed_commands: AllowedCommands,
+    ) -> Self {
+        Self {
+            cursors,
+            runtime: Arc::new(RwLock::new(Arc::new(RuntimeState {
+                data_sources,
+                allowed_commands,
+            }))),
+            retired: Arc::default(),
+            streams: CancellationToken::new(),
+        }
+    }
+
+    pub async fn connect(config: &Config) -> Result<Self, StartupError> {
+        let data_sources = DataSources::connect(config).await?;
+        let cursors =
+            CursorRegistry::new(config.cursors.idle_timeout(), config.cursors.max_per_client);
+
+        cursors.spawn_reaper();
+
+        Ok(Self::new(
+            data_sources,
+            cursors,
+            AllowedCommands::new(&config.commands.allowed),
+        ))
+    }
+
+    pub fn runtime(&self) -> Arc<RuntimeState> {
+        self.runtime.read().unwrap().clone()
+    }
+
+    pub fn swap(&self, runtime: RuntimeState) {
+        *self.runtime.write().unwrap() = Arc::new(runtime);
+    }
+
+    /// Shuts down clients a reload replaced once `grace` has passed, so
+    /// requests and cursors that started before the reload can finish.
+    pub fn retire(&self, clients: Vec<Client>, grace: Duration) {
+        for client in clients {
+            let id = {
+                let mut retired = self.retired.lock().unwrap();
+                let id = retired.next_id;
+
+                retired.next_id += 1;
+                retired.clients.insert(id, client);
+                id
+            };
+            let retired = self.retired.clone();
+
+            tokio::spawn(async move {
+                tokio::time::sleep(grace).await;
+
+                let client = retired.lock().unwrap().clients.remove(&id);
+
+                if let Some(client) = client {
+                    client.shutdown().await;
+                }
+            });
+        }
+    }
+
+    #[cfg(test)]
+    fn retired_clients(&self) -> usize {
+        self.retired.lock().unwrap().clients.len()
+    }
+
+    /// Ends open change streams. Call it before draining connections, since
+    /// a `/watch` response otherwise never completes on its own.
+    pub fn close_streams(&self) {
+        self.streams.cancel();
+    }
+
+    pub async fn shutdown(&self) {
+        self.cursors.kill_all();
+        self.runtime().data_sources.shutdown().await;
+
+        let retired = std::mem::take(&mut self.retired.lock().unwrap().clients);
+
+        for client in retired.into_values() {
+            client.shutdown().await;
+        }
+    }
+}
+
+pub async fn build(config: &Config) -> Result<Router, StartupError> {
+    Ok(router(AppState::connect(config).await?))
+}
+
+pub fn router(state: AppState) -> Router {
+    let resources = Router::new()
+        .route(
+            "/app/:app_id/endpoint/data/v1/action/:action",
+            post(data_api::action::handler),
+        )
+        .route(
+            "/db/:db/:collection",
+            get(rest::list_documents::handler).post(rest::create_document::handler),
+        )
+        .route(
+            "/db/:db/:collection/:id",
+            get(rest::get_document::handler)
+                .put(rest::replace_document::handler)
+                .patch(rest::update_document::handler)
+                .delete(rest::delete_document::handler),
+        )
+        .route("/gridfs/download", get(gridfs::download::handler))
+        .route(
+            "/gridfs/upload",
+            post(gridfs::upload::handler).layer(DefaultBodyLimit::disable()),
+        )
+        .route("/healthz", get(health::healthz::handler))
+        .route("/readyz", get(health::readyz::handler));
 
     Router::new()
+        .route("/aggregate", post(crud::aggregate::handler))
+        .route("/bulkWrite", post(crud::bulk_write::handler))
+        .route("/countDocuments", post(crud::count_documents::handler))
+        .route("/createCollection", post(admin::create_collection::handler))
+        .route("/createIndexes", post(indexes::create_indexes::handler))
         .route("/deleteMany", post(crud::delete_many::handler))
         .route("/deleteOne", post(crud::delete_one::handler))
+        .route("/distinct", post(crud::distinct::handler))
+        .route("/dropCollection", post(admin::drop_collection::handler))
+        .route("/dropDatabase", post(admin::drop_database::handler))
+        .route("/dropIndex", post(indexes::drop_index::handler))
+        .route(
+            "/estimatedDocumentCount",
+            post(crud::estimated_document_count::handler),
+        )
+        .route("/explain", post(crud::explain::handler))
         .route("/find", post(crud::find::handler))
         .route("/findOne", post(crud::find_one::handler))
+        .route(
+            "/findOneAndDelete",
+            post(crud::find_one_and_delete::handler),
+        )
+        .route(
+            "/findOneAndReplace",
+            post(crud::find_one_and_replace::handler),
+        )
+        .route(
+            "/findOneAndUpdate",
+            post(crud::find_one_and_update::handler),
+        )
+        .route("/getMore", post(cursors::get_more::handler))
+        .route("/getValidator", post(admin::get_validator::handler))
+        .route("/gridfs/delete", post(gridfs::delete::handler))
+        .route("/gridfs/find", post(gridfs::find::handler))
         .route("/insertMany", post(crud::insert_many::handler))
         .route("/insertOne", post(crud::insert_one::handler))
+        .route("/killCursors", post(cursors::kill_cursors::handler))
+        .route("/listCollections", post(admin::list_collections::handler))
+        .route("/listDatabases", post(admin::list_databases::handler))
+        .route("/listIndexes", post(indexes::list_indexes::handler))
+        .route("/renameCollection", post(admin::rename_collection::handler))
+        .route("/replaceOne", post(crud::replace_one::handler))
+        .route("/runCommand", post(admin::run_command::handler))
+        .route("/setValidator", post(admin::set_validator::handler))
+        .route("/transaction", post(crud::transaction::handler))
         .route("/updateMany", post(crud::update_many::handler))
         .route("/updateOne", post(crud::update_one::handler))
-        .with_state(client)
+        .route("/watch", post(change_stream::watch::handler))
+        .route_layer(middleware::from_fn(data_sources::read_body_data_source))
+        .merge(resources)
+        .with_state(state)
 }
@@ -0,0 +1,324 @@
+use std::{
+    fs,
+    path::Path,
+    time::{Duration, SystemTime},
+};
+
+use super::{AppState, RuntimeState, StartupError};
+use crate::{
+    admin::run_command::AllowedCommands,
+    config::{Config, ServerConfig},
+};
+
+const WATCH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Reloads the config on SIGHUP or when the config file changes. An invalid
+/// file is reported and the running config kept.
+pub fn spawn(state: AppState, config: Config) {
+    tokio::spawn(async move {
+        let (path, _) = Config::path();
+        let mut current = config;
+        let mut last_modified = modified(&path);
+        let mut interval = tokio::time::interval(WATCH_INTERVAL);
+        let mut hangup = Hangup::new();
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {
+                    let modified = modified(&path);
+
+                    if modified == last_modified {
+                        continue;
+                    }
+
+                    last_modified = modified;
+                }
+                () = hangup.recv() => {}
+            }
+
+            let config = match Config::load() {
+                Ok(config) => config,
+                Err(error) => {
+                    eprintln!("Config reload failed, keeping the running config: {error}");
+                    continue;
+                }
+            };
+
+            match apply(&state, &mut current, config).await {
+                Ok(true) => eprintln!("Config reloaded from {}", path.display()),
+                Ok(false) => {}
+                Err(error) => {
+                    eprintln!("Config reload failed, keeping the running config: {error}")
+                }
+            }
+        }
+    });
+}
+
+/// Swaps the runtime settings of `config` into `state` and records them in
+/// `current`. Returns whether anything changed.
+pub async fn apply(
+    state: &AppState,
+    current: &mut Config,
+    mut config: Config,
+) -> Result<bool, StartupError> {
+    for setting in restart_required(&current.server, &config.server) {
+        eprintln!("Config reload: {setting} changed, restart to apply it");
+    }
+
+    config.server = current.server.clone();
+
+    if config == *current {
+        return Ok(false);
+    }
+
+    let previous = state.runtime().data_sources.clone();
+    let data_sources = previous.reload(current, &config).await?;
+
+    state.swap(RuntimeState {
+        data_sources,
+        allowed_commands: AllowedCommands::new(&config.commands.allowed),
+    });
+    state.retire(
+        previous.retired(current, &config),
+        config.cursors.idle_timeout(),
+    );
+    state
+        .cursors
+        .set_limits(config.cursors.idle_timeout(), config.cursors.max_per_client);
+
+    *current = config;
+
+    Ok(true)
+}
+
+fn restart_required(current: &ServerConfig, config: &ServerConfig) -> Vec<&'static str> {
+    // Destructured so a new server setting has to be classified here.
+    let ServerConfig {
+        listen,
+        socket_mode,
+        shutdown_timeout_secs,
+        tls,
+    } = current;
+    let mut changed = Vec::new();
+
+    if *listen != config.listen {
+        changed.push("server.listen");
+    }
+
+    if *socket_mode != config.socket_mode {
+        changed.push("server.socket_mode");
+    }
+
+    if *shutdown_timeout_secs != config.shutdown_timeout_secs {
+        changed.push("server.shutdown_timeout_secs");
+    }
+
+    if *tls != config.tls {
+        changed.push("server.tls");
+    }
+
+    changed
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
+
+struct Hangup {
+    #[cfg(unix)]
+    signal: Option<tokio::signal::unix::Signal>,
+}
+
+impl Hangup {
+    fn new() -> Self {
+        Self {
+            #[cfg(unix)]
+            signal: tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup()).ok(),
+        }
+    }
+
+    async fn recv(&mut self) {
+        #[cfg(unix)]
+        if let Some(signal) = &mut self.signal {
+            if signal.recv().await.is_some() {
+                return;
+            }
+        }
+
+        std::future::pending().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::MongoConfig, cursors::CursorRegistry, data_sources::DataSources, mdb};
+    use std::collections::HashMap;
+
+    async fn state(config: &Config) -> AppState {
+        let client = mdb::get_client(&config.data_sources["default"])
+            .await
+            .unwrap();
+
+        AppState::new(
+            DataSources::new(
+                HashMap::from([("default".into(), client)]),
+                "default".into(),
+            ),
+            CursorRegistry::default(),
+            AllowedCommands::new(&config.commands.allowed),
+        )
+    }
+
+    #[tokio::test]
+    async fn applies_runtime_settings() {
+        let mut current = Config::default();
+        let state = state(&current).await;
+        let mut config = Config::default();
+
+        config.commands.allowed = vec!["ping".into()];
+        config.cursors.max_per_client = 5;
+
+        assert!(apply(&state, &mut current, config).await.unwrap());
+        assert!(state.runtime().allowed_commands.contains("ping"));
+        assert!(!state.runtime().allowed_commands.contains("hello"));
+        assert_eq!(current.cursors.max_per_client, 5);
+    }
+
+    #[tokio::test]
+    async fn ignores_restart_only_settings() {
+        let mut current = Config::default();
+        let state = state(&current).await;
+        let mut config = Config::default();
+
+        config.server.listen = "127.0.0.1:9090".into();
+        config.server.shutdown_timeout_secs = 1;
+
+        assert_eq!(
+            restart_required(&current.server, &config.server),
+            vec!["server.listen", "server.shutdown_timeout_secs"]
+        );
+        assert!(!apply(&state, &mut current, config).await.unwrap());
+        assert_eq!(current.server.listen, "0.0.0.0:8080");
+    }
+
+    #[tokio::test]
+    async fn keeps_running_config_when_data_source_fails() {
+        let mut current = Config::default();
+        let state = state(&current).await;
+        let mut config = Config::default();
+
+        config.data_sources.insert(
+            "default".into(),
+            MongoConfig {
+                uri: "mongodb://127.0.0.1:27018/?serverSelectionTimeoutMS=soon".into(),
+                ..MongoConfig::default()
+            },
+        );
+        config.commands.allowed = vec!["ping".into()];
+
+        assert!(matches!(
+            apply(&state, &mut current, config).await,
+            Err(StartupError::Connect { .. })
+        ));
+        assert!(state.runtime().allowed_commands.contains("hello"));
+        assert_eq!(current, Config::default());
+    }
+
+    #[tokio::test]
+    async fn reload_connects_once() {
+        let mut current = Config::default();
+        let state = state(&current).await;
+        let mut config = Config::default();
+
+        config.data_sources.insert(
+            "default".into(),
+            MongoConfig {
+                uri: "mongodb://127.0.0.1:1/?serverSelectionTimeoutMS=100".into(),
+                ..MongoConfig::default()
+            },
+        );
+
+        let started = std::time::Instant::now();
+
+        assert!(matches!(
+            apply(&state, &mut current, config).await,
+            Err(StartupError::Connect { .. })
+        ));
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+
+    #[tokio::test]
+    async fn shuts_down_retired_clients_after_grace() {
+        let config = Config::default();
+        let state = state(&config).await;
+        let client = mdb::get_client(&config.data_sources["default"])
+            .await
+            .unwrap();
+
+        state.retire(vec![client.clone()], Duration::from_millis(10));
+        assert_eq!(state.retired_clients(), 1);
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert_eq!(state.retired_clients(), 0);
+
+        let retired = state.runtime().data_sources.clone();
+
+        state.retire(
+            retired.retired(
+                &config,
+                &Config {
+                    data_sources: Default::default(),
+                    ..config.clone()
+                },
+            ),
+            Duration::from_secs(60),
+        );
+        assert_eq!(state.retired_clients(), 1);
+
+        state.shutdown().await;
+        assert_eq!(state.retired_clients(), 0);
+    }
+
+    #[tokio::test]
+    async fn retires_replaced_data_sources() {
+        let mut current = Config::default();
+
+        current.data_sources.insert(
+            "archive".into(),
+            MongoConfig {
+                uri: "mongodb://archive:27017".into(),
+                ..MongoConfig::default()
+            },
+        );
+
+        let mut clients = HashMap::new();
+
+        for (name, data_source) in &current.data_sources {
+            clients.insert(name.clone(), mdb::get_client(data_source).await.unwrap());
+        }
+
+        let data_sources = DataSources::new(clients, "default".into());
+        let mut config = current.clone();
+
+        assert!(data_sources.retired(&current, &config).is_empty());
+
+        config
+            .data_sources
+            .get_mut("archive")
+            .unwrap()
+            .max_pool_size = Some(5);
+        assert_eq!(data_sources.retired(&current, &config).len(), 1);
+
+        config.data_sources.remove("archive");
+        assert_eq!(data_sources.retired(&current, &config).len(), 1);
+
+        config
+            .data_sources
+            .get_mut("default")
+            .unwrap()
+            .max_pool_size = Some(5);
+        assert_eq!(data_sources.retired(&current, &config).len(), 2);
+    }
+}
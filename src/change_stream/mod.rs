@@ -0,0 +1,12 @@
+pub mod watch;
+
+use futures::stream::TakeUntil;
+use mongodb::{
+    bson::Document,
+    change_stream::{event::ChangeStreamEvent, ChangeStream},
+};
+use tokio_util::sync::WaitForCancellationFutureOwned;
+
+/// A change stream that ends when the server starts shutting down.
+pub type Watch =
+    TakeUntil<ChangeStream<ChangeStreamEvent<Document>>, WaitForCancellationFutureOwned>;
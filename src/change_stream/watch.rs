@@ -0,0 +1,68 @@
+use crate::{data_sources::DataSource, ejson::EJSON};
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use futures::StreamExt;
+use mongodb::{bson::Document, options::ChangeStreamOptions};
+use serde::Deserialize;
+use serde_json::json;
+use tokio_util::sync::CancellationToken;
+
+use super::Watch;
+
+#[derive(Debug, Deserialize)]
+pub struct WatchBody {
+    db: Option<String>,
+    collection: Option<String>,
+    pipeline: Option<Vec<Document>>,
+    options: Option<ChangeStreamOptions>,
+}
+
+pub async fn handler(
+    State(streams): State<CancellationToken>,
+    DataSource(client): DataSource,
+    EJSON(args): EJSON<WatchBody>,
+) -> Result<EJSON<Watch>, Response> {
+    let pipeline = args.pipeline.unwrap_or_default();
+
+    let result = match (args.db, args.collection) {
+        (Some(db), Some(collection)) => {
+            client
+                .database(&db)
+                .collection::<Document>(&collection)
+                .watch()
+                .pipeline(pipeline)
+                .with_options(args.options)
+                .await
+        }
+        (Some(db), None) => {
+            client
+                .database(&db)
+                .watch()
+                .pipeline(pipeline)
+                .with_options(args.options)
+                .await
+        }
+        (None, None) => {
+            client
+                .watch()
+                .pipeline(pipeline)
+                .with_options(args.options)
+                .await
+        }
+        (None, Some(_)) => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(json!({"message": "Collection requires a db"})),
+            )
+                .into_response())
+        }
+    };
+
+    let change_stream = result.map_err(|error| EJSON(error).into_response())?;
+
+    Ok(EJSON(change_stream.take_until(streams.cancelled_owned())))
+}
@@ -0,0 +1,677 @@
+use crate::admin::run_command::DEFAULT_ALLOWED_COMMANDS;
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    env, fmt, fs, io,
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use toml::{Table, Value};
+
+pub const CONFIG_PATH_VAR: &str = "RS_DATA_API_CONFIG";
+pub const DEFAULT_CONFIG_PATH: &str = "config.toml";
+pub const ENV_PREFIX: &str = "RS_DATA_API__";
+pub const DEFAULT_DATA_SOURCE: &str = "default";
+pub const UNIX_SOCKET_PREFIX: &str = "unix:";
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub server: ServerConfig,
+    pub default_data_source: String,
+    pub data_sources: BTreeMap<String, MongoConfig>,
+    pub cursors: CursorsConfig,
+    pub commands: CommandsConfig,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerConfig {
+    pub listen: String,
+    pub socket_mode: Option<u32>,
+    pub shutdown_timeout_secs: u64,
+    pub tls: Option<TlsConfig>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ListenAddress {
+    Tcp(SocketAddr),
+    Unix(PathBuf),
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TlsConfig {
+    pub cert_path: PathBuf,
+    pub key_path: PathBuf,
+    #[serde(default)]
+    pub client_ca_path: Option<PathBuf>,
+    #[serde(default = "default_reload_interval_secs")]
+    pub reload_interval_secs: u64,
+    #[serde(default = "default_handshake_timeout_secs")]
+    pub handshake_timeout_secs: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MongoConfig {
+    pub uri: String,
+    pub app_name: Option<String>,
+    pub min_pool_size: Option<u32>,
+    pub max_pool_size: Option<u32>,
+    pub max_idle_time_ms: Option<u64>,
+    pub connect_timeout_ms: Option<u64>,
+    pub server_selection_timeout_ms: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CursorsConfig {
+    pub idle_timeout_secs: u64,
+    pub max_per_client: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CommandsConfig {
+    pub allowed: Vec<String>,
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Read { path: PathBuf, source: io::Error },
+    Parse(toml::de::Error),
+    Env { name: String, message: String },
+    Invalid(Vec<String>),
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            server: ServerConfig::default(),
+            default_data_source: DEFAULT_DATA_SOURCE.into(),
+            data_sources: BTreeMap::from([(DEFAULT_DATA_SOURCE.into(), MongoConfig::default())]),
+            cursors: CursorsConfig::default(),
+            commands: CommandsConfig::default(),
+        }
+    }
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            listen: "0.0.0.0:8080".into(),
+            socket_mode: None,
+            shutdown_timeout_secs: 30,
+            tls: None,
+        }
+    }
+}
+
+impl Default for MongoConfig {
+    fn default() -> Self {
+        Self {
+            uri: "mongodb://127.0.0.1:27018".into(),
+            app_name: None,
+            min_pool_size: None,
+            max_pool_size: None,
+            max_idle_time_ms: None,
+            connect_timeout_ms: None,
+            server_selection_timeout_ms: None,
+        }
+    }
+}
+
+impl Default for CursorsConfig {
+    fn default() -> Self {
+        Self {
+            idle_timeout_secs: 600,
+            max_per_client: 100,
+        }
+    }
+}
+
+impl Default for CommandsConfig {
+    fn default() -> Self {
+        Self {
+            allowed: DEFAULT_ALLOWED_COMMANDS.map(String::from).to_vec(),
+        }
+    }
+}
+
+impl Config {
+    pub fn load() -> Result<Self, ConfigError> {
+        let (path, required) = Self::path();
+
+        Self::load_from(&path, required)
+    }
+
+    /// The file `load` reads, and whether it must exist.
+    pub fn path() -> (PathBuf, bool) {
+        match env::var(CONFIG_PATH_VAR) {
+            Ok(path) => (PathBuf::from(path), true),
+            Err(_) => (PathBuf::from(DEFAULT_CONFIG_PATH), false),
+        }
+    }
+
+    pub fn load_from(path: &Path, required: bool) -> Result<Self, ConfigError> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound && !required => String::new(),
+            Err(source) => {
+                return Err(ConfigError::Read {
+                    path: path.to_path_buf(),
+                    source,
+                })
+            }
+        };
+
+        Self::from_toml(&contents, env::vars())
+    }
+
+    pub fn from_toml(
+        contents: &str,
+        vars: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<Self, ConfigError> {
+        let mut table: Table = toml::from_str(contents).map_err(ConfigError::Parse)?;
+        let mut typed_overrides = Vec::new();
+
+        for (name, value) in vars {
+            if let Some(key) = name.strip_prefix(ENV_PREFIX) {
+                let path = override_path(&name, key)?;
+                let parsed = parse_override(&value);
+
+                if !parsed.is_str() {
+                    typed_overrides.push((name.clone(), path.clone(), value));
+                }
+
+                insert_override(&mut table, &name, &path, parsed)?;
+            }
+        }
+
+        let config = deserialize(table, typed_overrides)?;
+
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mut errors = Vec::new();
+
+        match self.server.listen_address() {
+            Ok(ListenAddress::Tcp(_)) if self.server.socket_mode.is_some() => {
+                errors.push("server.socket_mode: only applies to unix: listen addresses".into());
+            }
+            Ok(_) => {}
+            Err(error) => errors.push(format!("server.listen: {error}")),
+        }
+
+        if self.server.socket_mode.is_some_and(|mode| mode > 0o777) {
+            errors.push("server.socket_mode: must be a permission mode such as 0o660".into());
+        }
+
+        if let Some(tls) = &self.server.tls {
+            tls.validate("server.tls", &mut errors);
+        }
+
+        if self.data_sources.is_empty() {
+            errors.push("data_sources: at least one data source is required".into());
+        } else if !self.data_sources.contains_key(&self.default_data_source) {
+            errors.push(format!(
+                "default_data_source: \"{}\" is not a configured data source",
+                self.default_data_source
+            ));
+        }
+
+        for (name, data_source) in &self.data_sources {
+            data_source.validate(&format!("data_sources.{name}"), &mut errors);
+        }
+
+        if self.cursors.idle_timeout_secs == 0 {
+            errors.push("cursors.idle_timeout_secs: must be greater than 0".into());
+        }
+
+        if self.cursors.max_per_client == 0 {
+            errors.push("cursors.max_per_client: must be greater than 0".into());
+        }
+
+        if self
+            .commands
+            .allowed
+            .iter()
+            .any(|name| name.trim().is_empty())
+        {
+            errors.push("commands.allowed: command names must not be empty".into());
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError::Invalid(errors))
+        }
+    }
+}
+
+impl MongoConfig {
+    fn validate(&self, prefix: &str, errors: &mut Vec<String>) {
+        if !self.uri.starts_with("mongodb://") && !self.uri.starts_with("mongodb+srv://") {
+            errors.push(format!(
+                "{prefix}.uri: must start with mongodb:// or mongodb+srv://"
+            ));
+        }
+
+        if self.max_pool_size == Some(0) {
+            errors.push(format!("{prefix}.max_pool_size: must be greater than 0"));
+        }
+
+        if let (Some(min), Some(max)) = (self.min_pool_size, self.max_pool_size) {
+            if min > max {
+                errors.push(format!(
+                    "{prefix}.min_pool_size: must not exceed {prefix}.max_pool_size"
+                ));
+            }
+        }
+
+        for (name, value) in [
+            ("connect_timeout_ms", self.connect_timeout_ms),
+            (
+                "server_selection_timeout_ms",
+                self.server_selection_timeout_ms,
+            ),
+        ] {
+            if value == Some(0) {
+                errors.push(format!("{prefix}.{name}: must be greater than 0"));
+            }
+        }
+    }
+}
+
+impl ServerConfig {
+    pub fn listen_address(&self) -> Result<ListenAddress, String> {
+        if let Some(path) = self.listen.strip_prefix(UNIX_SOCKET_PREFIX) {
+            return match path {
+                "" => Err("unix socket path must not be empty".into()),
+                path => Ok(ListenAddress::Unix(PathBuf::from(path))),
+            };
+        }
+
+        self.listen.parse().map(ListenAddress::Tcp).map_err(|_| {
+            format!(
+                "\"{}\" is not a valid socket address or unix:<path>",
+                self.listen
+            )
+        })
+    }
+
+    pub fn shutdown_timeout(&self) -> Duration {
+        Duration::from_secs(self.shutdown_timeout_secs)
+    }
+}
+
+impl TlsConfig {
+    pub fn reload_interval(&self) -> Duration {
+        Duration::from_secs(self.reload_interval_secs)
+    }
+
+    pub fn handshake_timeout(&self) -> Duration {
+        Duration::from_secs(self.handshake_timeout_secs)
+    }
+
+    fn validate(&self, prefix: &str, errors: &mut Vec<String>) {
+        for (name, path) in [
+            ("cert_path", Some(&self.cert_path)),
+            ("key_path", Some(&self.key_path)),
+            ("client_ca_path", self.client_ca_path.as_ref()),
+        ] {
+            if let Some(path) = path {
+                if !path.is_file() {
+                    errors.push(format!(
+                        "{prefix}.{name}: {} is not a readable file",
+                        path.display()
+                    ));
+                }
+            }
+        }
+
+        if self.reload_interval_secs == 0 {
+            errors.push(format!(
+                "{prefix}.reload_interval_secs: must be greater than 0"
+            ));
+        }
+
+        if self.handshake_timeout_secs == 0 {
+            errors.push(format!(
+                "{prefix}.handshake_timeout_secs: must be greater than 0"
+            ));
+        }
+    }
+}
+
+impl CursorsConfig {
+    pub fn idle_timeout(&self) -> Duration {
+        Duration::from_secs(self.idle_timeout_secs)
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Read { path, source } => {
+                write!(f, "Failed to read config file {}: {source}", path.display())
+            }
+            ConfigError::Parse(error) => write!(f, "Failed to parse config: {error}"),
+            ConfigError::Env { name, message } => {
+                write!(f, "Invalid environment override {name}: {message}")
+            }
+            ConfigError::Invalid(errors) => {
+                write!(f, "Invalid config:")?;
+
+                for error in errors {
+                    write!(f, "\n  - {error}")?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+fn default_reload_interval_secs() -> u64 {
+    30
+}
+
+fn default_handshake_timeout_secs() -> u64 {
+    10
+}
+
+fn override_path(name: &str, key: &str) -> Result<Vec<String>, ConfigError> {
+    let path: Vec<String> = key.split("__").map(str::to_lowercase).collect();
+
+    if path.iter().any(String::is_empty) {
+        return Err(ConfigError::Env {
+            name: name.into(),
+            message: "expected RS_DATA_API__<SECTION>__<KEY>".into(),
+        });
+    }
+
+    Ok(path)
+}
+
+fn insert_override(
+    table: &mut Table,
+    name: &str,
+    path: &[String],
+    value: Value,
+) -> Result<(), ConfigError> {
+    let (last, sections) = path.split_last().unwrap();
+    let mut current = table;
+
+    for section in sections {
+        let entry = current
+            .entry(section.clone())
+            .or_insert_with(|| Value::Table(Table::new()));
+
+        current = entry.as_table_mut().ok_or_else(|| ConfigError::Env {
+            name: name.into(),
+            message: format!("{section} is not a table"),
+        })?;
+    }
+
+    current.insert(last.clone(), value);
+
+    Ok(())
+}
+
+/// Deserializes the merged table. An override that parsed as a TOML number,
+/// bool or array but lands on a string field, such as `APP_NAME=123`, is
+/// retried as the raw string.
+fn deserialize(
+    mut table: Table,
+    mut typed_overrides: Vec<(String, Vec<String>, String)>,
+) -> Result<Config, ConfigError> {
+    loop {
+        let error = match serde_path_to_error::deserialize(Value::Table(table.clone())) {
+            Ok(config) => return Ok(config),
+            Err(error) => error,
+        };
+        let failed = error.path().to_string();
+        let Some(index) = typed_overrides
+            .iter()
+            .position(|(_, path, _)| path.join(".") == failed)
+        else {
+            return Err(ConfigError::Parse(error.into_inner()));
+        };
+        let (name, path, value) = typed_overrides.swap_remove(index);
+
+        insert_override(&mut table, &name, &path, Value::String(value))?;
+    }
+}
+
+fn parse_override(value: &str) -> Value {
+    toml::from_str::<Table>(&format!("value = {value}"))
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| Value::String(value.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn defaults_are_valid() {
+        let config = Config::from_toml("", vars(&[])).unwrap();
+
+        assert_eq!(config.server.listen, "0.0.0.0:8080");
+        assert_eq!(config.default_data_source, "default");
+        assert_eq!(
+            config.data_sources["default"].uri,
+            "mongodb://127.0.0.1:27018"
+        );
+        assert_eq!(config.cursors.max_per_client, 100);
+        assert!(config.commands.allowed.contains(&"ping".to_string()));
+    }
+
+    #[test]
+    fn reads_toml() {
+        let contents = r#"
+            [server]
+            listen = "127.0.0.1:3000"
+
+            [data_sources.default]
+            uri = "mongodb://db.internal:27017"
+            max_pool_size = 20
+
+            [commands]
+            allowed = ["ping"]
+        "#;
+        let config = Config::from_toml(contents, vars(&[])).unwrap();
+
+        assert_eq!(config.server.listen, "127.0.0.1:3000");
+        assert_eq!(
+            config.data_sources["default"].uri,
+            "mongodb://db.internal:27017"
+        );
+        assert_eq!(config.data_sources["default"].max_pool_size, Some(20));
+        assert_eq!(config.commands.allowed, vec!["ping"]);
+    }
+
+    #[test]
+    fn env_overrides_toml() {
+        let contents = r#"
+            [data_sources.default]
+            uri = "mongodb://db.internal:27017"
+        "#;
+        let config = Config::from_toml(
+            contents,
+            vars(&[
+                (
+                    "RS_DATA_API__DATA_SOURCES__DEFAULT__URI",
+                    "mongodb://override:27017",
+                ),
+                ("RS_DATA_API__CURSORS__MAX_PER_CLIENT", "5"),
+                ("RS_DATA_API__COMMANDS__ALLOWED", r#"["ping", "hello"]"#),
+                ("UNRELATED", "ignored"),
+            ]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.data_sources["default"].uri,
+            "mongodb://override:27017"
+        );
+        assert_eq!(config.cursors.max_per_client, 5);
+        assert_eq!(config.commands.allowed, vec!["ping", "hello"]);
+    }
+
+    #[test]
+    fn env_overrides_keep_numeric_strings() {
+        let config = Config::from_toml(
+            "",
+            vars(&[
+                ("RS_DATA_API__DATA_SOURCES__DEFAULT__APP_NAME", "123"),
+                ("RS_DATA_API__DATA_SOURCES__DEFAULT__MAX_POOL_SIZE", "20"),
+            ]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.data_sources["default"].app_name.as_deref(),
+            Some("123")
+        );
+        assert_eq!(config.data_sources["default"].max_pool_size, Some(20));
+    }
+
+    #[test]
+    fn env_overrides_report_mistyped_values() {
+        let error = Config::from_toml(
+            "",
+            vars(&[("RS_DATA_API__CURSORS__MAX_PER_CLIENT", "many")]),
+        )
+        .unwrap_err();
+
+        assert!(matches!(error, ConfigError::Parse(_)));
+        assert!(error.to_string().contains("in `cursors.max_per_client`"));
+    }
+
+    #[test]
+    fn reports_every_invalid_setting() {
+        let contents = r#"
+            [server]
+            listen = "nowhere"
+
+            [data_sources.default]
+            uri = "http://localhost"
+            min_pool_size = 10
+            max_pool_size = 5
+        "#;
+        let error = Config::from_toml(contents, vars(&[])).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Invalid config:\n  \
+             - server.listen: \"nowhere\" is not a valid socket address or unix:<path>\n  \
+             - data_sources.default.uri: must start with mongodb:// or mongodb+srv://\n  \
+             - data_sources.default.min_pool_size: must not exceed data_sources.default.max_pool_size"
+        );
+    }
+
+    #[test]
+    fn reads_named_data_sources() {
+        let contents = r#"
+            default_data_source = "operational"
+
+            [data_sources.operational]
+            uri = "mongodb://operational:27017"
+
+            [data_sources.analytics]
+            uri = "mongodb://analytics:27017"
+        "#;
+        let config = Config::from_toml(contents, vars(&[])).unwrap();
+
+        assert_eq!(config.default_data_source, "operational");
+        assert_eq!(
+            config.data_sources.keys().collect::<Vec<_>>(),
+            vec!["analytics", "operational"]
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_default_data_source() {
+        let contents = r#"
+            default_data_source = "archive"
+
+            [data_sources.operational]
+            uri = "mongodb://operational:27017"
+        "#;
+        let error = Config::from_toml(contents, vars(&[])).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Invalid config:\n  - default_data_source: \"archive\" is not a configured data source"
+        );
+    }
+
+    #[test]
+    fn validates_tls_paths() {
+        let contents = r#"
+            [server.tls]
+            cert_path = "/nonexistent/server.pem"
+            key_path = "/nonexistent/server.key"
+            reload_interval_secs = 0
+            handshake_timeout_secs = 0
+        "#;
+        let error = Config::from_toml(contents, vars(&[])).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Invalid config:\n  \
+             - server.tls.cert_path: /nonexistent/server.pem is not a readable file\n  \
+             - server.tls.key_path: /nonexistent/server.key is not a readable file\n  \
+             - server.tls.reload_interval_secs: must be greater than 0\n  \
+             - server.tls.handshake_timeout_secs: must be greater than 0"
+        );
+    }
+
+    #[test]
+    fn reads_unix_listen_address() {
+        let contents = r#"
+            [server]
+            listen = "unix:/run/rs-data-api.sock"
+            socket_mode = 0o660
+        "#;
+        let config = Config::from_toml(contents, vars(&[])).unwrap();
+
+        assert_eq!(
+            config.server.listen_address().unwrap(),
+            ListenAddress::Unix("/run/rs-data-api.sock".into())
+        );
+        assert_eq!(config.server.socket_mode, Some(0o660));
+
+        let error = Config::from_toml("[server]\nlisten = \"unix:\"", vars(&[])).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Invalid config:\n  - server.listen: unix socket path must not be empty"
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_settings() {
+        let error =
+            Config::from_toml("[data_sources.default]\nurl = \"x\"", vars(&[])).unwrap_err();
+
+        assert!(error.to_string().contains("unknown field `url`"));
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{
+    cursors::{registry::DEFAULT_BATCH_SIZE, CursorOwner, CursorRegistry},
+    data_sources::DataSource,
+    ejson::EJSON,
+};
+use axum::{
+    extract::State,
+    response::{IntoResponse, Response},
+};
+use futures::stream::TryStreamExt;
+use mongodb::{bson::Document, options::AggregateOptions, Client, Cursor};
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AggregateBody {
+    pub(crate) db: String,
+    pub(crate) collection: String,
+    pub(crate) pipeline: Vec<Document>,
+    pub(crate) options: Option<AggregateOptions>,
+    pub(crate) cursor: Option<bool>,
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    State(cursors): State<CursorRegistry>,
+    CursorOwner(owner): CursorOwner,
+    EJSON(args): EJSON<AggregateBody>,
+) -> Result<Response, Response> {
+    let batch_size = args
+        .options
+        .as_ref()
+        .and_then(|options| options.batch_size)
+        .unwrap_or(DEFAULT_BATCH_SIZE);
+
+    let cursor_mode = args.cursor.unwrap_or(false);
+    let cursor = open_cursor(&client, args)
+        .await
+        .map_err(|error| EJSON(error).into_response())?;
+
+    if cursor_mode {
+        let result = cursors
+            .open(&owner, cursor, batch_size)
+            .await
+            .map_err(IntoResponse::into_response)?;
+
+        return Ok(EJSON(result).into_response());
+    }
+
+    let result: Vec<Document> = cursor
+        .try_collect()
+        .await
+        .map_err(|error| EJSON(error).into_response())?;
+
+    Ok(EJSON(result).into_response())
+}
+
+pub(crate) async fn open_cursor(
+    client: &Client,
+    args: AggregateBody,
+) -> Result<Cursor<Document>, mongodb::error::Error> {
+    client
+        .database(&args.db)
+        .collection::<Document>(&args.collection)
+        .aggregate(args.pipeline)
+        .with_options(args.options)
+        .await
+}
@@ -0,0 +1,165 @@
+use crate::{data_sources::DataSource, ejson::EJSON};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use mongodb::{
+    bson::{Array, Bson, Document},
+    error::{Error, ErrorKind},
+    options::{
+        BulkWriteOptions, DeleteManyModel, DeleteOneModel, InsertOneModel, ReplaceOneModel,
+        UpdateManyModel, UpdateOneModel, WriteModel,
+    },
+    Namespace,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+use super::{results::BulkWriteResult, validate};
+
+#[derive(Debug, Deserialize)]
+pub struct BulkWriteBody {
+    db: String,
+    collection: String,
+    operations: Vec<WriteOperation>,
+    options: Option<BulkWriteOptions>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WriteOperation {
+    InsertOne(InsertOneOperation),
+    UpdateOne(UpdateOperation),
+    UpdateMany(UpdateOperation),
+    ReplaceOne(ReplaceOneOperation),
+    DeleteOne(DeleteOperation),
+    DeleteMany(DeleteOperation),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct InsertOneOperation {
+    document: Document,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateOperation {
+    filter: Document,
+    update: Document,
+    array_filters: Option<Array>,
+    collation: Option<Document>,
+    hint: Option<Bson>,
+    upsert: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReplaceOneOperation {
+    filter: Document,
+    replacement: Document,
+    collation: Option<Document>,
+    hint: Option<Bson>,
+    upsert: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeleteOperation {
+    filter: Document,
+    collation: Option<Document>,
+    hint: Option<Bson>,
+}
+
+impl WriteOperation {
+    fn into_model(self, namespace: Namespace) -> WriteModel {
+        match self {
+            WriteOperation::InsertOne(op) => InsertOneModel::builder()
+                .namespace(namespace)
+                .document(op.document)
+                .build()
+                .into(),
+            WriteOperation::UpdateOne(op) => UpdateOneModel::builder()
+                .namespace(namespace)
+                .filter(op.filter)
+                .update(op.update)
+                .array_filters(op.array_filters)
+                .collation(op.collation)
+                .hint(op.hint)
+                .upsert(op.upsert)
+                .build()
+                .into(),
+            WriteOperation::UpdateMany(op) => UpdateManyModel::builder()
+                .namespace(namespace)
+                .filter(op.filter)
+                .update(op.update)
+                .array_filters(op.array_filters)
+                .collation(op.collation)
+                .hint(op.hint)
+                .upsert(op.upsert)
+                .build()
+                .into(),
+            WriteOperation::ReplaceOne(op) => ReplaceOneModel::builder()
+                .namespace(namespace)
+                .filter(op.filter)
+                .replacement(op.replacement)
+                .collation(op.collation)
+                .hint(op.hint)
+                .upsert(op.upsert)
+                .build()
+                .into(),
+            WriteOperation::DeleteOne(op) => DeleteOneModel::builder()
+                .namespace(namespace)
+                .filter(op.filter)
+                .collation(op.collation)
+                .hint(op.hint)
+                .build()
+                .into(),
+            WriteOperation::DeleteMany(op) => DeleteManyModel::builder()
+                .namespace(namespace)
+                .filter(op.filter)
+                .collation(op.collation)
+                .hint(op.hint)
+                .build()
+                .into(),
+        }
+    }
+}
+
+/// Runs the operations with the server's `bulkWrite` command, which needs
+/// MongoDB 8.0 or later. Older servers get a 501 saying so.
+pub async fn handler(
+    DataSource(client): DataSource,
+    EJSON(args): EJSON<BulkWriteBody>,
+) -> Result<EJSON<BulkWriteResult>, Response> {
+    for operation in &args.operations {
+        if let WriteOperation::ReplaceOne(op) = operation {
+            validate::replacement_document(&op.replacement)?;
+        }
+    }
+
+    let namespace = Namespace::new(args.db, args.collection);
+    let models: Vec<WriteModel> = args
+        .operations
+        .into_iter()
+        .map(|operation| operation.into_model(namespace.clone()))
+        .collect();
+
+    let result = client
+        .bulk_write(models)
+        .with_options(args.options)
+        .verbose_results()
+        .await
+        .map_err(error_response)?;
+
+    Ok(EJSON(result.into()))
+}
+
+fn error_response(error: Error) -> Response {
+    match *error.kind {
+        ErrorKind::IncompatibleServer { .. } => (
+            StatusCode::NOT_IMPLEMENTED,
+            Json(json!({"message": "bulkWrite requires MongoDB 8.0 or later"})),
+        )
+            .into_response(),
+        _ => EJSON(error).into_response(),
+    }
+}
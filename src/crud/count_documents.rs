@@ -0,0 +1,30 @@
+use crate::{data_sources::DataSource, ejson::EJSON};
+use mongodb::{bson::Document, options::CountOptions};
+use serde::Deserialize;
+
+use super::results::CountResult;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CountDocumentsBody {
+    pub(crate) db: String,
+    pub(crate) collection: String,
+    pub(crate) filter: Document,
+    pub(crate) options: Option<CountOptions>,
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    EJSON(args): EJSON<CountDocumentsBody>,
+) -> Result<EJSON<CountResult>, EJSON<mongodb::error::Error>> {
+    let count = client
+        .database(&args.db)
+        .collection::<Document>(&args.collection)
+        .count_documents(args.filter)
+        .with_options(args.options)
+        .await
+        .map_err(EJSON)?;
+
+    Ok(EJSON(CountResult {
+        count: count as i64,
+    }))
+}
@@ -1,19 +1,18 @@
-use crate::ejson::EJSON;
-use axum::extract::State;
-use mongodb::{bson::Document, options::DeleteOptions, results::DeleteResult, Client};
+use crate::{data_sources::DataSource, ejson::EJSON};
+use mongodb::{bson::Document, options::DeleteOptions, results::DeleteResult};
 use serde::Deserialize;
 
-#[derive(Debug, Deserialize)]
-pub struct FindBody {
-    db: String,
-    collection: String,
-    filter: Document,
-    options: Option<DeleteOptions>,
+#[derive(Clone, Debug, Deserialize)]
+pub struct DeleteManyBody {
+    pub(crate) db: String,
+    pub(crate) collection: String,
+    pub(crate) filter: Document,
+    pub(crate) options: Option<DeleteOptions>,
 }
 
 pub async fn handler(
-    State(client): State<Client>,
-    EJSON(args): EJSON<FindBody>,
+    DataSource(client): DataSource,
+    EJSON(args): EJSON<DeleteManyBody>,
 ) -> Result<EJSON<DeleteResult>, EJSON<mongodb::error::Error>> {
     let result = client
         .database(&args.db)
@@ -21,7 +20,7 @@ pub async fn handler(
         .delete_many(args.filter)
         .with_options(args.options)
         .await
-        .map_err(|error| EJSON(error))?;
+        .map_err(EJSON)?;
 
     Ok(EJSON(result))
 }
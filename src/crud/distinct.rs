@@ -0,0 +1,31 @@
+use crate::{data_sources::DataSource, ejson::EJSON};
+use mongodb::{
+    bson::{Bson, Document},
+    options::DistinctOptions,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DistinctBody {
+    db: String,
+    collection: String,
+    field_name: String,
+    filter: Document,
+    options: Option<DistinctOptions>,
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    EJSON(args): EJSON<DistinctBody>,
+) -> Result<EJSON<Vec<Bson>>, EJSON<mongodb::error::Error>> {
+    let result = client
+        .database(&args.db)
+        .collection::<Document>(&args.collection)
+        .distinct(args.field_name, args.filter)
+        .with_options(args.options)
+        .await
+        .map_err(EJSON)?;
+
+    Ok(EJSON(result))
+}
@@ -0,0 +1,29 @@
+use crate::{data_sources::DataSource, ejson::EJSON};
+use mongodb::{bson::Document, options::EstimatedDocumentCountOptions};
+use serde::Deserialize;
+
+use super::results::CountResult;
+
+#[derive(Debug, Deserialize)]
+pub struct EstimatedDocumentCountBody {
+    db: String,
+    collection: String,
+    options: Option<EstimatedDocumentCountOptions>,
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    EJSON(args): EJSON<EstimatedDocumentCountBody>,
+) -> Result<EJSON<CountResult>, EJSON<mongodb::error::Error>> {
+    let count = client
+        .database(&args.db)
+        .collection::<Document>(&args.collection)
+        .estimated_document_count()
+        .with_options(args.options)
+        .await
+        .map_err(EJSON)?;
+
+    Ok(EJSON(CountResult {
+        count: count as i64,
+    }))
+}
@@ -0,0 +1,196 @@
+use crate::{data_sources::DataSource, ejson::EJSON};
+use mongodb::{
+    bson::{self, doc, Bson, Document},
+    error::Result as MongoResult,
+    options::{DeleteOptions, FindOptions, UpdateOptions},
+};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    aggregate::AggregateBody, count_documents::CountDocumentsBody, delete_many::DeleteManyBody,
+    delete_one::DeleteOneBody, find::FindBody, find_one::FindOneBody, update_many::UpdateManyBody,
+    update_one::UpdateOneBody,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct ExplainBody {
+    operation: ExplainOperation,
+    verbosity: Option<Verbosity>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExplainOperation {
+    Aggregate(AggregateBody),
+    CountDocuments(CountDocumentsBody),
+    DeleteMany(DeleteManyBody),
+    DeleteOne(DeleteOneBody),
+    Find(FindBody),
+    FindOne(FindOneBody),
+    UpdateMany(UpdateManyBody),
+    UpdateOne(UpdateOneBody),
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Verbosity {
+    #[default]
+    QueryPlanner,
+    ExecutionStats,
+    AllPlansExecution,
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    EJSON(args): EJSON<ExplainBody>,
+) -> Result<EJSON<Document>, EJSON<mongodb::error::Error>> {
+    let (db, command) = explained_command(args.operation).map_err(EJSON)?;
+    let verbosity =
+        bson::to_bson(&args.verbosity.unwrap_or_default()).map_err(|error| EJSON(error.into()))?;
+
+    let result = client
+        .database(&db)
+        .run_command(doc! {"explain": command, "verbosity": verbosity})
+        .await
+        .map_err(EJSON)?;
+
+    Ok(EJSON(result))
+}
+
+fn explained_command(operation: ExplainOperation) -> MongoResult<(String, Document)> {
+    let result = match operation {
+        ExplainOperation::Aggregate(args) => {
+            let mut command = doc! {"aggregate": args.collection, "pipeline": args.pipeline};
+
+            command.extend(bson::to_document(&args.options.unwrap_or_default())?);
+            command
+                .entry("cursor".to_string())
+                .or_insert(doc! {}.into());
+
+            (args.db, command)
+        }
+        ExplainOperation::CountDocuments(args) => {
+            let options = args.options.unwrap_or_default();
+            let mut command = doc! {"count": args.collection, "query": args.filter};
+
+            insert_some(
+                &mut command,
+                "limit",
+                options.limit.map(|limit| limit as i64),
+            );
+            insert_some(&mut command, "skip", options.skip.map(|skip| skip as i64));
+            insert_some(&mut command, "hint", to_bson(options.hint)?);
+            insert_some(&mut command, "collation", to_bson(options.collation)?);
+            insert_some(&mut command, "comment", options.comment);
+
+            (args.db, command)
+        }
+        ExplainOperation::DeleteMany(args) => (
+            args.db,
+            delete_command(args.collection, args.filter, args.options, 0)?,
+        ),
+        ExplainOperation::DeleteOne(args) => (
+            args.db,
+            delete_command(args.collection, args.filter, args.options, 1)?,
+        ),
+        ExplainOperation::Find(args) => (
+            args.db,
+            find_command(
+                args.collection,
+                args.filter,
+                args.options.unwrap_or_default(),
+            )?,
+        ),
+        ExplainOperation::FindOne(args) => {
+            let mut options: FindOptions = args.options.unwrap_or_default().into();
+
+            options.limit = Some(1);
+
+            (
+                args.db,
+                find_command(args.collection, args.filter, options)?,
+            )
+        }
+        ExplainOperation::UpdateMany(args) => (
+            args.db,
+            update_command(args.collection, args.query, args.update, args.options, true)?,
+        ),
+        ExplainOperation::UpdateOne(args) => (
+            args.db,
+            update_command(
+                args.collection,
+                args.query,
+                args.update,
+                args.options,
+                false,
+            )?,
+        ),
+    };
+
+    Ok(result)
+}
+
+fn find_command(
+    collection: String,
+    filter: Document,
+    options: FindOptions,
+) -> MongoResult<Document> {
+    let mut command = doc! {"find": collection, "filter": filter};
+
+    command.extend(bson::to_document(&options)?);
+
+    Ok(command)
+}
+
+fn update_command(
+    collection: String,
+    query: Document,
+    update: Document,
+    options: Option<UpdateOptions>,
+    multi: bool,
+) -> MongoResult<Document> {
+    let options = options.unwrap_or_default();
+    let mut statement = doc! {"q": query, "u": update, "multi": multi};
+
+    insert_some(&mut statement, "upsert", options.upsert);
+    insert_some(&mut statement, "arrayFilters", options.array_filters);
+    insert_some(&mut statement, "collation", to_bson(options.collation)?);
+    insert_some(&mut statement, "hint", to_bson(options.hint)?);
+
+    let mut command = doc! {"update": collection, "updates": [statement]};
+
+    insert_some(&mut command, "let", options.let_vars);
+    insert_some(&mut command, "comment", options.comment);
+
+    Ok(command)
+}
+
+fn delete_command(
+    collection: String,
+    filter: Document,
+    options: Option<DeleteOptions>,
+    limit: i32,
+) -> MongoResult<Document> {
+    let options = options.unwrap_or_default();
+    let mut statement = doc! {"q": filter, "limit": limit};
+
+    insert_some(&mut statement, "collation", to_bson(options.collation)?);
+    insert_some(&mut statement, "hint", to_bson(options.hint)?);
+
+    let mut command = doc! {"delete": collection, "deletes": [statement]};
+
+    insert_some(&mut command, "let", options.let_vars);
+    insert_some(&mut command, "comment", options.comment);
+
+    Ok(command)
+}
+
+fn to_bson<T: Serialize>(value: Option<T>) -> MongoResult<Option<Bson>> {
+    Ok(value.map(|value| bson::to_bson(&value)).transpose()?)
+}
+
+fn insert_some(document: &mut Document, key: &str, value: Option<impl Into<Bson>>) {
+    if let Some(value) = value {
+        document.insert(key, value);
+    }
+}
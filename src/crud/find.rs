@@ -1,30 +1,80 @@
-use crate::ejson::EJSON;
-use axum::extract::State;
+use crate::{
+    cursors::{registry::DEFAULT_BATCH_SIZE, CursorOwner, CursorRegistry},
+    data_sources::DataSource,
+    ejson::EJSON,
+};
+use axum::{
+    extract::State,
+    http::{header, HeaderMap},
+    response::{IntoResponse, Response},
+};
 use futures::stream::TryStreamExt;
-use mongodb::{bson::Document, options::FindOptions, Client};
+use mongodb::{bson::Document, options::FindOptions, Client, Cursor};
 use serde::Deserialize;
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize)]
 pub struct FindBody {
-    db: String,
-    collection: String,
-    filter: Document,
-    options: Option<FindOptions>,
+    pub(crate) db: String,
+    pub(crate) collection: String,
+    pub(crate) filter: Document,
+    pub(crate) options: Option<FindOptions>,
+    pub(crate) cursor: Option<bool>,
 }
 
 pub async fn handler(
-    State(client): State<Client>,
+    DataSource(client): DataSource,
+    State(cursors): State<CursorRegistry>,
+    CursorOwner(owner): CursorOwner,
+    headers: HeaderMap,
     EJSON(args): EJSON<FindBody>,
-) -> Result<EJSON<Vec<Document>>, EJSON<mongodb::error::Error>> {
-    let cursor = client
+) -> Result<Response, Response> {
+    let batch_size = args
+        .options
+        .as_ref()
+        .and_then(|options| options.batch_size)
+        .unwrap_or(DEFAULT_BATCH_SIZE);
+
+    let cursor_mode = args.cursor.unwrap_or(false);
+    let cursor = open_cursor(&client, args)
+        .await
+        .map_err(|error| EJSON(error).into_response())?;
+
+    if cursor_mode {
+        let result = cursors
+            .open(&owner, cursor, batch_size)
+            .await
+            .map_err(IntoResponse::into_response)?;
+
+        return Ok(EJSON(result).into_response());
+    }
+
+    if accepts_ndjson(&headers) {
+        return Ok(EJSON(cursor).into_response());
+    }
+
+    let result: Vec<Document> = cursor
+        .try_collect()
+        .await
+        .map_err(|error| EJSON(error).into_response())?;
+
+    Ok(EJSON(result).into_response())
+}
+
+pub(crate) async fn open_cursor(
+    client: &Client,
+    args: FindBody,
+) -> Result<Cursor<Document>, mongodb::error::Error> {
+    client
         .database(&args.db)
         .collection::<Document>(&args.collection)
         .find(args.filter)
         .with_options(args.options)
         .await
-        .map_err(|error| EJSON(error))?;
-
-    let result: Vec<Document> = cursor.try_collect().await.map_err(|error| EJSON(error))?;
+}
 
-    Ok(EJSON(result))
+fn accepts_ndjson(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::ACCEPT)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.contains("application/x-ndjson"))
 }
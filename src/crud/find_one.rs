@@ -1,18 +1,17 @@
-use crate::ejson::EJSON;
-use axum::extract::State;
-use mongodb::{bson::Document, options::FindOneOptions, Client};
+use crate::{data_sources::DataSource, ejson::EJSON};
+use mongodb::{bson::Document, options::FindOneOptions};
 use serde::Deserialize;
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize)]
 pub struct FindOneBody {
-    db: String,
-    collection: String,
-    filter: Document,
-    options: Option<FindOneOptions>,
+    pub(crate) db: String,
+    pub(crate) collection: String,
+    pub(crate) filter: Document,
+    pub(crate) options: Option<FindOneOptions>,
 }
 
 pub async fn handler(
-    State(client): State<Client>,
+    DataSource(client): DataSource,
     EJSON(args): EJSON<FindOneBody>,
 ) -> Result<EJSON<Option<Document>>, EJSON<mongodb::error::Error>> {
     let result = client
@@ -21,7 +20,7 @@ pub async fn handler(
         .find_one(args.filter)
         .with_options(args.options)
         .await
-        .map_err(|error| EJSON(error))?;
+        .map_err(EJSON)?;
 
     Ok(EJSON(result))
 }
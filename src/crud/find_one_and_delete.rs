@@ -0,0 +1,26 @@
+use crate::{data_sources::DataSource, ejson::EJSON};
+use mongodb::{bson::Document, options::FindOneAndDeleteOptions};
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct FindOneAndDeleteBody {
+    pub(crate) db: String,
+    pub(crate) collection: String,
+    pub(crate) filter: Document,
+    pub(crate) options: Option<FindOneAndDeleteOptions>,
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    EJSON(args): EJSON<FindOneAndDeleteBody>,
+) -> Result<EJSON<Option<Document>>, EJSON<mongodb::error::Error>> {
+    let result = client
+        .database(&args.db)
+        .collection::<Document>(&args.collection)
+        .find_one_and_delete(args.filter)
+        .with_options(args.options)
+        .await
+        .map_err(EJSON)?;
+
+    Ok(EJSON(result))
+}
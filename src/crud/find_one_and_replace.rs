@@ -0,0 +1,32 @@
+use crate::{data_sources::DataSource, ejson::EJSON};
+use axum::response::{IntoResponse, Response};
+use mongodb::{bson::Document, options::FindOneAndReplaceOptions};
+use serde::Deserialize;
+
+use super::validate;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct FindOneAndReplaceBody {
+    pub(crate) db: String,
+    pub(crate) collection: String,
+    pub(crate) filter: Document,
+    pub(crate) replacement: Document,
+    pub(crate) options: Option<FindOneAndReplaceOptions>,
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    EJSON(args): EJSON<FindOneAndReplaceBody>,
+) -> Result<EJSON<Option<Document>>, Response> {
+    validate::replacement_document(&args.replacement)?;
+
+    let result = client
+        .database(&args.db)
+        .collection::<Document>(&args.collection)
+        .find_one_and_replace(args.filter, args.replacement)
+        .with_options(args.options)
+        .await
+        .map_err(|error| EJSON(error).into_response())?;
+
+    Ok(EJSON(result))
+}
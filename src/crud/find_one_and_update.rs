@@ -0,0 +1,27 @@
+use crate::{data_sources::DataSource, ejson::EJSON};
+use mongodb::{bson::Document, options::FindOneAndUpdateOptions};
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct FindOneAndUpdateBody {
+    pub(crate) db: String,
+    pub(crate) collection: String,
+    pub(crate) filter: Document,
+    pub(crate) update: Document,
+    pub(crate) options: Option<FindOneAndUpdateOptions>,
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    EJSON(args): EJSON<FindOneAndUpdateBody>,
+) -> Result<EJSON<Option<Document>>, EJSON<mongodb::error::Error>> {
+    let result = client
+        .database(&args.db)
+        .collection::<Document>(&args.collection)
+        .find_one_and_update(args.filter, args.update)
+        .with_options(args.options)
+        .await
+        .map_err(EJSON)?;
+
+    Ok(EJSON(result))
+}
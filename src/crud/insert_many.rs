@@ -1,21 +1,18 @@
-use axum::extract::State;
-use mongodb::{
-    self, bson::Document, options::InsertManyOptions, results::InsertManyResult, Client,
-};
+use mongodb::{self, bson::Document, options::InsertManyOptions, results::InsertManyResult};
 use serde::Deserialize;
 
-use crate::ejson::EJSON;
+use crate::{data_sources::DataSource, ejson::EJSON};
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize)]
 pub struct InsertManyBody {
-    db: String,
-    collection: String,
-    documents: Vec<Document>,
-    options: Option<InsertManyOptions>,
+    pub(crate) db: String,
+    pub(crate) collection: String,
+    pub(crate) documents: Vec<Document>,
+    pub(crate) options: Option<InsertManyOptions>,
 }
 
 pub async fn handler(
-    State(client): State<Client>,
+    DataSource(client): DataSource,
     EJSON(args): EJSON<InsertManyBody>,
 ) -> Result<EJSON<InsertManyResult>, EJSON<mongodb::error::Error>> {
     let result = client
@@ -24,7 +21,7 @@ pub async fn handler(
         .insert_many(args.documents)
         .with_options(args.options)
         .await
-        .map_err(|error| EJSON(error))?;
+        .map_err(EJSON)?;
 
     Ok(EJSON(result))
 }
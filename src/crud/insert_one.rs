@@ -1,19 +1,18 @@
-use axum::extract::State;
-use mongodb::{self, bson::Document, options::InsertOneOptions, results::InsertOneResult, Client};
+use mongodb::{self, bson::Document, options::InsertOneOptions, results::InsertOneResult};
 use serde::Deserialize;
 
-use crate::ejson::EJSON;
+use crate::{data_sources::DataSource, ejson::EJSON};
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize)]
 pub struct InsertOneBody {
-    db: String,
-    collection: String,
-    document: Document,
-    options: Option<InsertOneOptions>,
+    pub(crate) db: String,
+    pub(crate) collection: String,
+    pub(crate) document: Document,
+    pub(crate) options: Option<InsertOneOptions>,
 }
 
 pub async fn handler(
-    State(client): State<Client>,
+    DataSource(client): DataSource,
     EJSON(args): EJSON<InsertOneBody>,
 ) -> Result<EJSON<InsertOneResult>, EJSON<mongodb::error::Error>> {
     let result = client
@@ -22,7 +21,7 @@ pub async fn handler(
         .insert_one(args.document)
         .with_options(args.options)
         .await
-        .map_err(|error| EJSON(error))?;
+        .map_err(EJSON)?;
 
     Ok(EJSON(result))
 }
@@ -1,8 +1,21 @@
+pub mod aggregate;
+pub mod bulk_write;
+pub mod count_documents;
 pub mod delete_many;
 pub mod delete_one;
+pub mod distinct;
+pub mod estimated_document_count;
+pub mod explain;
 pub mod find;
 pub mod find_one;
+pub mod find_one_and_delete;
+pub mod find_one_and_replace;
+pub mod find_one_and_update;
 pub mod insert_many;
 pub mod insert_one;
+pub mod replace_one;
+pub mod results;
+pub mod transaction;
 pub mod update_many;
 pub mod update_one;
+pub mod validate;
@@ -0,0 +1,64 @@
+use crate::{data_sources::DataSource, ejson::EJSON};
+use axum::response::{IntoResponse, Response};
+use mongodb::{bson::Document, options::ReplaceOptions, results::UpdateResult};
+use serde::Deserialize;
+
+use super::validate::{self, ValidationError};
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ReplaceOneBody {
+    pub(crate) db: String,
+    pub(crate) collection: String,
+    pub(crate) filter: Document,
+    pub(crate) replacement: Document,
+    pub(crate) options: Option<ReplaceOptions>,
+}
+
+#[derive(Debug)]
+pub enum ReplaceOneError {
+    Invalid(ValidationError),
+    Driver(mongodb::error::Error),
+}
+
+impl From<ValidationError> for ReplaceOneError {
+    fn from(error: ValidationError) -> Self {
+        Self::Invalid(error)
+    }
+}
+
+impl From<mongodb::error::Error> for ReplaceOneError {
+    fn from(error: mongodb::error::Error) -> Self {
+        Self::Driver(error)
+    }
+}
+
+impl IntoResponse for ReplaceOneError {
+    fn into_response(self) -> Response {
+        match self {
+            Self::Invalid(error) => error.into_response(),
+            Self::Driver(error) => EJSON(error).into_response(),
+        }
+    }
+}
+
+impl From<ReplaceOneError> for Response {
+    fn from(error: ReplaceOneError) -> Self {
+        error.into_response()
+    }
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    EJSON(args): EJSON<ReplaceOneBody>,
+) -> Result<EJSON<UpdateResult>, ReplaceOneError> {
+    validate::replacement_document(&args.replacement)?;
+
+    let result = client
+        .database(&args.db)
+        .collection::<Document>(&args.collection)
+        .replace_one(args.filter, args.replacement)
+        .with_options(args.options)
+        .await?;
+
+    Ok(EJSON(result))
+}
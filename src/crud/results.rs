@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+use mongodb::{
+    bson::Document,
+    error::{BulkWriteError, PartialBulkWriteResult, WriteConcernError, WriteError},
+    results::{
+        DeleteResult, InsertManyResult, InsertOneResult, SummaryBulkWriteResult, UpdateResult,
+        VerboseBulkWriteResult,
+    },
+};
+use serde::{Serialize, Serializer};
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CountResult {
+    pub count: i64,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkWriteResult {
+    pub inserted_count: i64,
+    pub matched_count: i64,
+    pub modified_count: i64,
+    pub upserted_count: i64,
+    pub deleted_count: i64,
+    #[serde(serialize_with = "serialize_indexed_map")]
+    pub insert_results: HashMap<usize, InsertOneResult>,
+    #[serde(serialize_with = "serialize_indexed_map")]
+    pub update_results: HashMap<usize, UpdateResult>,
+    #[serde(serialize_with = "serialize_indexed_map")]
+    pub delete_results: HashMap<usize, DeleteResult>,
+}
+
+impl From<SummaryBulkWriteResult> for BulkWriteResult {
+    fn from(summary: SummaryBulkWriteResult) -> Self {
+        Self {
+            inserted_count: summary.inserted_count,
+            matched_count: summary.matched_count,
+            modified_count: summary.modified_count,
+            upserted_count: summary.upserted_count,
+            deleted_count: summary.deleted_count,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<VerboseBulkWriteResult> for BulkWriteResult {
+    fn from(verbose: VerboseBulkWriteResult) -> Self {
+        Self {
+            insert_results: verbose.insert_results,
+            update_results: verbose.update_results,
+            delete_results: verbose.delete_results,
+            ..verbose.summary.into()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkWriteFailure {
+    #[serde(serialize_with = "serialize_indexed_map")]
+    pub write_errors: HashMap<usize, WriteError>,
+    pub write_concern_errors: Vec<WriteConcernError>,
+    pub partial_result: Option<BulkWriteResult>,
+}
+
+impl From<BulkWriteError> for BulkWriteFailure {
+    fn from(error: BulkWriteError) -> Self {
+        let partial_result = error.partial_result.map(|result| match result {
+            PartialBulkWriteResult::Summary(summary) => summary.into(),
+            PartialBulkWriteResult::Verbose(verbose) => verbose.into(),
+        });
+
+        Self {
+            write_errors: error.write_errors,
+            write_concern_errors: error.write_concern_errors,
+            partial_result,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum OperationResult {
+    Delete(DeleteResult),
+    Document(Option<Document>),
+    Documents(Vec<Document>),
+    InsertMany(InsertManyResult),
+    InsertOne(InsertOneResult),
+    Update(UpdateResult),
+}
+
+#[derive(Debug, Serialize)]
+pub struct TransactionResult {
+    pub results: Vec<OperationResult>,
+}
+
+fn serialize_indexed_map<S: Serializer, T: Serialize>(
+    map: &HashMap<usize, T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut entries: Vec<(&usize, &T)> = map.iter().collect();
+
+    entries.sort_by_key(|(index, _)| **index);
+
+    serializer.collect_map(
+        entries
+            .into_iter()
+            .map(|(index, value)| (index.to_string(), value)),
+    )
+}
@@ -0,0 +1,248 @@
+use crate::{data_sources::DataSource, ejson::EJSON};
+use axum::response::{IntoResponse, Response};
+use futures::stream::TryStreamExt;
+use mongodb::{
+    bson::Document,
+    error::{
+        Result as MongoResult, TRANSIENT_TRANSACTION_ERROR, UNKNOWN_TRANSACTION_COMMIT_RESULT,
+    },
+    options::TransactionOptions,
+    Client, ClientSession, Collection,
+};
+use serde::Deserialize;
+
+use super::{
+    delete_many::DeleteManyBody,
+    delete_one::DeleteOneBody,
+    find::FindBody,
+    find_one::FindOneBody,
+    find_one_and_delete::FindOneAndDeleteBody,
+    find_one_and_replace::FindOneAndReplaceBody,
+    find_one_and_update::FindOneAndUpdateBody,
+    insert_many::InsertManyBody,
+    insert_one::InsertOneBody,
+    replace_one::ReplaceOneBody,
+    results::{OperationResult, TransactionResult},
+    update_many::UpdateManyBody,
+    update_one::UpdateOneBody,
+    validate,
+};
+
+const MAX_TRANSACTION_ATTEMPTS: usize = 3;
+const MAX_COMMIT_ATTEMPTS: usize = 3;
+
+#[derive(Debug, Deserialize)]
+pub struct TransactionBody {
+    operations: Vec<TransactionOperation>,
+    options: Option<TransactionOptions>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionOperation {
+    DeleteMany(DeleteManyBody),
+    DeleteOne(DeleteOneBody),
+    Find(FindBody),
+    FindOne(FindOneBody),
+    FindOneAndDelete(FindOneAndDeleteBody),
+    FindOneAndReplace(FindOneAndReplaceBody),
+    FindOneAndUpdate(FindOneAndUpdateBody),
+    InsertMany(InsertManyBody),
+    InsertOne(InsertOneBody),
+    ReplaceOne(ReplaceOneBody),
+    UpdateMany(UpdateManyBody),
+    UpdateOne(UpdateOneBody),
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    EJSON(args): EJSON<TransactionBody>,
+) -> Result<EJSON<TransactionResult>, Response> {
+    for operation in &args.operations {
+        match operation {
+            TransactionOperation::ReplaceOne(op) => {
+                validate::replacement_document(&op.replacement)?
+            }
+            TransactionOperation::FindOneAndReplace(op) => {
+                validate::replacement_document(&op.replacement)?
+            }
+            _ => (),
+        }
+    }
+
+    let mut session = client
+        .start_session()
+        .await
+        .map_err(|error| EJSON(error).into_response())?;
+
+    let results = run_transaction(&client, &mut session, args.operations, args.options)
+        .await
+        .map_err(|error| EJSON(error).into_response())?;
+
+    Ok(EJSON(TransactionResult { results }))
+}
+
+async fn run_transaction(
+    client: &Client,
+    session: &mut ClientSession,
+    operations: Vec<TransactionOperation>,
+    options: Option<TransactionOptions>,
+) -> MongoResult<Vec<OperationResult>> {
+    let mut attempt = 1;
+
+    loop {
+        session
+            .start_transaction()
+            .with_options(options.clone())
+            .await?;
+
+        let error = match run_operations(client, session, operations.clone()).await {
+            Ok(results) => match commit_transaction(session).await {
+                Ok(()) => return Ok(results),
+                Err(error) => error,
+            },
+            Err(error) => {
+                let _ = session.abort_transaction().await;
+                error
+            }
+        };
+
+        if attempt == MAX_TRANSACTION_ATTEMPTS || !error.contains_label(TRANSIENT_TRANSACTION_ERROR)
+        {
+            return Err(error);
+        }
+
+        attempt += 1;
+    }
+}
+
+async fn commit_transaction(session: &mut ClientSession) -> MongoResult<()> {
+    let mut attempt = 1;
+
+    loop {
+        match session.commit_transaction().await {
+            Err(error)
+                if attempt < MAX_COMMIT_ATTEMPTS
+                    && error.contains_label(UNKNOWN_TRANSACTION_COMMIT_RESULT) =>
+            {
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+async fn run_operations(
+    client: &Client,
+    session: &mut ClientSession,
+    operations: Vec<TransactionOperation>,
+) -> MongoResult<Vec<OperationResult>> {
+    let mut results = Vec::with_capacity(operations.len());
+
+    for operation in operations {
+        results.push(run_operation(client, session, operation).await?);
+    }
+
+    Ok(results)
+}
+
+fn collection(client: &Client, db: &str, collection: &str) -> Collection<Document> {
+    client.database(db).collection::<Document>(collection)
+}
+
+async fn run_operation(
+    client: &Client,
+    session: &mut ClientSession,
+    operation: TransactionOperation,
+) -> MongoResult<OperationResult> {
+    let result = match operation {
+        TransactionOperation::DeleteMany(args) => OperationResult::Delete(
+            collection(client, &args.db, &args.collection)
+                .delete_many(args.filter)
+                .with_options(args.options)
+                .session(&mut *session)
+                .await?,
+        ),
+        TransactionOperation::DeleteOne(args) => OperationResult::Delete(
+            collection(client, &args.db, &args.collection)
+                .delete_one(args.filter)
+                .with_options(args.options)
+                .session(&mut *session)
+                .await?,
+        ),
+        TransactionOperation::Find(args) => {
+            let mut cursor = collection(client, &args.db, &args.collection)
+                .find(args.filter)
+                .with_options(args.options)
+                .session(&mut *session)
+                .await?;
+
+            OperationResult::Documents(cursor.stream(session).try_collect().await?)
+        }
+        TransactionOperation::FindOne(args) => OperationResult::Document(
+            collection(client, &args.db, &args.collection)
+                .find_one(args.filter)
+                .with_options(args.options)
+                .session(&mut *session)
+                .await?,
+        ),
+        TransactionOperation::FindOneAndDelete(args) => OperationResult::Document(
+            collection(client, &args.db, &args.collection)
+                .find_one_and_delete(args.filter)
+                .with_options(args.options)
+                .session(&mut *session)
+                .await?,
+        ),
+        TransactionOperation::FindOneAndReplace(args) => OperationResult::Document(
+            collection(client, &args.db, &args.collection)
+                .find_one_and_replace(args.filter, args.replacement)
+                .with_options(args.options)
+                .session(&mut *session)
+                .await?,
+        ),
+        TransactionOperation::FindOneAndUpdate(args) => OperationResult::Document(
+            collection(client, &args.db, &args.collection)
+                .find_one_and_update(args.filter, args.update)
+                .with_options(args.options)
+                .session(&mut *session)
+                .await?,
+        ),
+        TransactionOperation::InsertMany(args) => OperationResult::InsertMany(
+            collection(client, &args.db, &args.collection)
+                .insert_many(args.documents)
+                .with_options(args.options)
+                .session(&mut *session)
+                .await?,
+        ),
+        TransactionOperation::InsertOne(args) => OperationResult::InsertOne(
+            collection(client, &args.db, &args.collection)
+                .insert_one(args.document)
+                .with_options(args.options)
+                .session(&mut *session)
+                .await?,
+        ),
+        TransactionOperation::ReplaceOne(args) => OperationResult::Update(
+            collection(client, &args.db, &args.collection)
+                .replace_one(args.filter, args.replacement)
+                .with_options(args.options)
+                .session(&mut *session)
+                .await?,
+        ),
+        TransactionOperation::UpdateMany(args) => OperationResult::Update(
+            collection(client, &args.db, &args.collection)
+                .update_many(args.query, args.update)
+                .with_options(args.options)
+                .session(&mut *session)
+                .await?,
+        ),
+        TransactionOperation::UpdateOne(args) => OperationResult::Update(
+            collection(client, &args.db, &args.collection)
+                .update_one(args.query, args.update)
+                .with_options(args.options)
+                .session(&mut *session)
+                .await?,
+        ),
+    };
+
+    Ok(result)
+}
@@ -1,19 +1,18 @@
-use crate::ejson::EJSON;
-use axum::extract::State;
-use mongodb::{bson::Document, options::UpdateOptions, results::UpdateResult, Client};
+use crate::{data_sources::DataSource, ejson::EJSON};
+use mongodb::{bson::Document, options::UpdateOptions, results::UpdateResult};
 use serde::Deserialize;
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize)]
 pub struct UpdateOneBody {
-    db: String,
-    collection: String,
-    query: Document,
-    update: Document,
-    options: Option<UpdateOptions>,
+    pub(crate) db: String,
+    pub(crate) collection: String,
+    pub(crate) query: Document,
+    pub(crate) update: Document,
+    pub(crate) options: Option<UpdateOptions>,
 }
 
 pub async fn handler(
-    State(client): State<Client>,
+    DataSource(client): DataSource,
     EJSON(args): EJSON<UpdateOneBody>,
 ) -> Result<EJSON<UpdateResult>, EJSON<mongodb::error::Error>> {
     let result = client
@@ -22,7 +21,7 @@ pub async fn handler(
         .update_one(args.query, args.update)
         .with_options(args.options)
         .await
-        .map_err(|error| EJSON(error))?;
+        .map_err(EJSON)?;
 
     Ok(EJSON(result))
 }
@@ -0,0 +1,78 @@
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use mongodb::bson::Document;
+use serde_json::json;
+
+/// A request argument the server would reject, caught before it is sent.
+#[derive(Debug)]
+pub struct ValidationError {
+    message: String,
+}
+
+impl ValidationError {
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl IntoResponse for ValidationError {
+    fn into_response(self) -> Response {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(json!({"message": self.message})),
+        )
+            .into_response()
+    }
+}
+
+impl From<ValidationError> for Response {
+    fn from(error: ValidationError) -> Self {
+        error.into_response()
+    }
+}
+
+pub fn replacement_document(replacement: &Document) -> Result<(), ValidationError> {
+    match replacement.keys().find(|key| key.starts_with('$')) {
+        Some(key) => Err(ValidationError {
+            message: format!("Replacement document must not contain update operators: {key}"),
+        }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::to_bytes;
+    use mongodb::bson::doc;
+    use serde_json::Value;
+
+    #[test]
+    fn replacement_document_plain() {
+        let replacement = doc! {"name": "john", "tags": {"$literal": 1}};
+
+        assert!(replacement_document(&replacement).is_ok());
+    }
+
+    #[tokio::test]
+    async fn replacement_document_with_operator() {
+        let replacement = doc! {"name": "john", "$set": {"age": 30}};
+        let res = replacement_document(&replacement)
+            .err()
+            .unwrap()
+            .into_response();
+        let (parts, body) = res.into_parts();
+        let body_bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let body_json: Value = serde_json::from_slice(&body_bytes).unwrap();
+        let message = body_json.get("message").unwrap();
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+        assert_eq!(
+            message,
+            "Replacement document must not contain update operators: $set"
+        );
+    }
+}
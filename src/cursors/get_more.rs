@@ -0,0 +1,25 @@
+use crate::ejson::EJSON;
+use axum::extract::State;
+use serde::Deserialize;
+
+use super::{registry::DEFAULT_BATCH_SIZE, CursorBatch, CursorError, CursorOwner, CursorRegistry};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetMoreBody {
+    cursor_id: String,
+    batch_size: Option<u32>,
+}
+
+pub async fn handler(
+    State(cursors): State<CursorRegistry>,
+    CursorOwner(owner): CursorOwner,
+    EJSON(args): EJSON<GetMoreBody>,
+) -> Result<EJSON<CursorBatch>, CursorError> {
+    let batch_size = args.batch_size.unwrap_or(DEFAULT_BATCH_SIZE);
+    let result = cursors
+        .get_more(&owner, &args.cursor_id, batch_size)
+        .await?;
+
+    Ok(EJSON(result))
+}
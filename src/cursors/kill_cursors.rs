@@ -0,0 +1,37 @@
+use crate::ejson::EJSON;
+use axum::extract::State;
+use serde::{Deserialize, Serialize};
+
+use super::{CursorError, CursorOwner, CursorRegistry};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KillCursorsBody {
+    cursor_ids: Vec<String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KillCursorsResult {
+    pub cursors_killed: Vec<String>,
+    pub cursors_not_found: Vec<String>,
+    pub cursors_alive: Vec<String>,
+}
+
+pub async fn handler(
+    State(cursors): State<CursorRegistry>,
+    CursorOwner(owner): CursorOwner,
+    EJSON(args): EJSON<KillCursorsBody>,
+) -> EJSON<KillCursorsResult> {
+    let mut result = KillCursorsResult::default();
+
+    for cursor_id in args.cursor_ids {
+        match cursors.kill(&owner, &cursor_id) {
+            Ok(()) => result.cursors_killed.push(cursor_id),
+            Err(CursorError::Busy(_)) => result.cursors_alive.push(cursor_id),
+            Err(_) => result.cursors_not_found.push(cursor_id),
+        }
+    }
+
+    EJSON(result)
+}
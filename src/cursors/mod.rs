@@ -0,0 +1,5 @@
+pub mod get_more;
+pub mod kill_cursors;
+pub mod registry;
+
+pub use registry::{CursorBatch, CursorError, CursorOwner, CursorRegistry};
@@ -0,0 +1,438 @@
+use axum::{
+    async_trait,
+    extract::{ConnectInfo, FromRequestParts},
+    http::{request::Parts, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use futures::stream::TryStreamExt;
+use mongodb::{bson::Document, Cursor};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::Serialize;
+use serde_json::json;
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    net::SocketAddr,
+    sync::{Arc, Mutex, RwLock, Weak},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    ejson::EJSON,
+    mdb,
+    server::{ClientIdentity, UnixPeer},
+};
+
+pub const DEFAULT_BATCH_SIZE: u32 = 101;
+pub const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(600);
+pub const DEFAULT_MAX_CURSORS_PER_CLIENT: usize = 100;
+
+const REAPER_INTERVAL: Duration = Duration::from_secs(10);
+
+struct CursorEntry {
+    cursor: Option<Cursor<Document>>,
+    buffered: Vec<Document>,
+    owner: String,
+    last_used: Instant,
+}
+
+#[derive(Clone, Copy)]
+struct CursorLimits {
+    idle_timeout: Duration,
+    max_per_client: usize,
+}
+
+#[derive(Clone)]
+pub struct CursorRegistry {
+    entries: Arc<Mutex<HashMap<String, CursorEntry>>>,
+    limits: Arc<RwLock<CursorLimits>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CursorBatch {
+    pub cursor_id: Option<String>,
+    pub batch: Vec<Document>,
+}
+
+#[derive(Debug)]
+pub enum CursorError {
+    NotFound(String),
+    Busy(String),
+    TooManyCursors(usize),
+    Driver(mongodb::error::Error),
+}
+
+pub struct CursorOwner(pub String);
+
+impl CursorRegistry {
+    pub fn new(idle_timeout: Duration, max_per_client: usize) -> Self {
+        Self {
+            entries: Arc::new(Mutex::new(HashMap::new())),
+            limits: Arc::new(RwLock::new(CursorLimits {
+                idle_timeout,
+                max_per_client,
+            })),
+        }
+    }
+
+    /// Applies to cursors opened or reaped from now on; cursors over a
+    /// lowered per-client limit stay open until used up or idle.
+    pub fn set_limits(&self, idle_timeout: Duration, max_per_client: usize) {
+        *self.limits.write().unwrap() = CursorLimits {
+            idle_timeout,
+            max_per_client,
+        };
+    }
+
+    fn limits(&self) -> CursorLimits {
+        *self.limits.read().unwrap()
+    }
+
+    pub async fn open(
+        &self,
+        owner: &str,
+        mut cursor: Cursor<Document>,
+        batch_size: u32,
+    ) -> Result<CursorBatch, CursorError> {
+        let mut batch = Vec::new();
+
+        next_batch(&mut cursor, &mut batch, batch_size)
+            .await
+            .map_err(CursorError::Driver)?;
+
+        if !cursor.has_next() {
+            return Ok(CursorBatch {
+                cursor_id: None,
+                batch,
+            });
+        }
+
+        let mut entries = self.entries.lock().unwrap();
+        let open = entries
+            .values()
+            .filter(|entry| entry.owner == owner)
+            .count();
+
+        let max_per_client = self.limits().max_per_client;
+
+        if open >= max_per_client {
+            return Err(CursorError::TooManyCursors(max_per_client));
+        }
+
+        let cursor_id = new_cursor_id();
+
+        entries.insert(
+            cursor_id.clone(),
+            CursorEntry {
+                cursor: Some(cursor),
+                buffered: Vec::new(),
+                owner: owner.to_string(),
+                last_used: Instant::now(),
+            },
+        );
+
+        Ok(CursorBatch {
+            cursor_id: Some(cursor_id),
+            batch,
+        })
+    }
+
+    pub async fn get_more(
+        &self,
+        owner: &str,
+        cursor_id: &str,
+        batch_size: u32,
+    ) -> Result<CursorBatch, CursorError> {
+        let (mut cursor, mut batch) = self.checkout(owner, cursor_id)?;
+        let checkout = Checkout {
+            registry: self,
+            cursor_id,
+            checked_in: false,
+        };
+
+        if let Err(error) = next_batch(&mut cursor, &mut batch, batch_size).await {
+            if mdb::is_transient(&error) {
+                checkout.checkin(cursor, batch);
+            }
+
+            return Err(CursorError::Driver(error));
+        }
+
+        if !cursor.has_next() {
+            return Ok(CursorBatch {
+                cursor_id: None,
+                batch,
+            });
+        }
+
+        checkout.checkin(cursor, Vec::new());
+
+        Ok(CursorBatch {
+            cursor_id: Some(cursor_id.to_string()),
+            batch,
+        })
+    }
+
+    pub fn kill(&self, owner: &str, cursor_id: &str) -> Result<(), CursorError> {
+        let mut entries = self.entries.lock().unwrap();
+
+        match entries.get(cursor_id) {
+            Some(entry) if entry.owner == owner && entry.cursor.is_none() => {
+                Err(CursorError::Busy(cursor_id.to_string()))
+            }
+            Some(entry) if entry.owner == owner => {
+                entries.remove(cursor_id);
+                Ok(())
+            }
+            _ => Err(CursorError::NotFound(cursor_id.to_string())),
+        }
+    }
+
+    /// Drops every open cursor; the driver kills them server-side.
+    pub fn kill_all(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    pub fn reap_idle(&self) {
+        remove_idle(&self.entries, self.limits().idle_timeout);
+    }
+
+    pub fn spawn_reaper(&self) {
+        let entries: Weak<_> = Arc::downgrade(&self.entries);
+        let limits = self.limits.clone();
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(REAPER_INTERVAL);
+
+            loop {
+                interval.tick().await;
+
+                let Some(entries) = entries.upgrade() else {
+                    break;
+                };
+
+                let idle_timeout = limits.read().unwrap().idle_timeout;
+
+                remove_idle(&entries, idle_timeout);
+            }
+        });
+    }
+
+    fn checkout(
+        &self,
+        owner: &str,
+        cursor_id: &str,
+    ) -> Result<(Cursor<Document>, Vec<Document>), CursorError> {
+        let mut entries = self.entries.lock().unwrap();
+
+        let entry = entries
+            .get_mut(cursor_id)
+            .filter(|entry| entry.owner == owner)
+            .ok_or_else(|| CursorError::NotFound(cursor_id.to_string()))?;
+        let cursor = entry
+            .cursor
+            .take()
+            .ok_or_else(|| CursorError::Busy(cursor_id.to_string()))?;
+
+        Ok((cursor, std::mem::take(&mut entry.buffered)))
+    }
+
+    fn checkin(&self, cursor_id: &str, cursor: Cursor<Document>, buffered: Vec<Document>) {
+        if let Some(entry) = self.entries.lock().unwrap().get_mut(cursor_id) {
+            entry.cursor = Some(cursor);
+            entry.buffered = buffered;
+            entry.last_used = Instant::now();
+        }
+    }
+}
+
+/// A cursor taken out by `get_more`. Unless checked back in, dropping it
+/// removes the entry, including when the request is cancelled mid-getMore.
+struct Checkout<'a> {
+    registry: &'a CursorRegistry,
+    cursor_id: &'a str,
+    checked_in: bool,
+}
+
+impl Checkout<'_> {
+    fn checkin(mut self, cursor: Cursor<Document>, buffered: Vec<Document>) {
+        self.registry.checkin(self.cursor_id, cursor, buffered);
+        self.checked_in = true;
+    }
+}
+
+impl Drop for Checkout<'_> {
+    fn drop(&mut self) {
+        if !self.checked_in {
+            self.registry.entries.lock().unwrap().remove(self.cursor_id);
+        }
+    }
+}
+
+impl Default for CursorRegistry {
+    fn default() -> Self {
+        Self::new(DEFAULT_IDLE_TIMEOUT, DEFAULT_MAX_CURSORS_PER_CLIENT)
+    }
+}
+
+// Cursor ids are bearer tokens for getMore/killCursors, so they must not be
+// predictable from other ids the way ObjectIds are.
+fn new_cursor_id() -> String {
+    let mut bytes = [0u8; 16];
+
+    SystemRandom::new().fill(&mut bytes).unwrap();
+
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn remove_idle(entries: &Mutex<HashMap<String, CursorEntry>>, idle_timeout: Duration) {
+    entries
+        .lock()
+        .unwrap()
+        .retain(|_, entry| entry.cursor.is_none() || entry.last_used.elapsed() < idle_timeout);
+}
+
+async fn next_batch(
+    cursor: &mut Cursor<Document>,
+    batch: &mut Vec<Document>,
+    batch_size: u32,
+) -> Result<(), mongodb::error::Error> {
+    while batch.len() < batch_size as usize {
+        match cursor.try_next().await? {
+            Some(document) => batch.push(document),
+            None => break,
+        }
+    }
+
+    Ok(())
+}
+
+impl IntoResponse for CursorError {
+    fn into_response(self) -> Response {
+        match self {
+            CursorError::NotFound(cursor_id) => (
+                StatusCode::NOT_FOUND,
+                Json(json!({"message": format!("Cursor not found: {cursor_id}")})),
+            )
+                .into_response(),
+            CursorError::Busy(cursor_id) => (
+                StatusCode::CONFLICT,
+                Json(json!({"message": format!("Cursor is busy: {cursor_id}")})),
+            )
+                .into_response(),
+            CursorError::TooManyCursors(max) => (
+                StatusCode::TOO_MANY_REQUESTS,
+                Json(json!({"message": format!("Too many open cursors, limit is {max}")})),
+            )
+                .into_response(),
+            CursorError::Driver(error) => EJSON(error).into_response(),
+        }
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for CursorOwner
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let extensions = &parts.extensions;
+
+        let owner = if let Some(identity) = extensions.get::<ClientIdentity>() {
+            format!("tls:{}", identity.subject)
+        } else if let Some(peer) = extensions.get::<UnixPeer>() {
+            format!("unix:{}", peer.uid)
+        } else if let Some(ConnectInfo(address)) = extensions.get::<ConnectInfo<SocketAddr>>() {
+            format!("ip:{}", address.ip())
+        } else {
+            "anonymous".to_string()
+        };
+
+        Ok(CursorOwner(owner))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::Request;
+
+    async fn owner(request: Request<()>) -> String {
+        let (mut parts, _) = request.into_parts();
+        let CursorOwner(owner) = CursorOwner::from_request_parts(&mut parts, &())
+            .await
+            .unwrap();
+
+        owner
+    }
+
+    #[test]
+    fn dropped_checkout_removes_cursor() {
+        let cursors = CursorRegistry::default();
+
+        cursors.entries.lock().unwrap().insert(
+            "checked-out".into(),
+            CursorEntry {
+                cursor: None,
+                buffered: Vec::new(),
+                owner: "ip:10.0.0.1".into(),
+                last_used: Instant::now(),
+            },
+        );
+
+        assert!(matches!(
+            cursors.kill("ip:10.0.0.1", "checked-out"),
+            Err(CursorError::Busy(_))
+        ));
+
+        drop(Checkout {
+            registry: &cursors,
+            cursor_id: "checked-out",
+            checked_in: false,
+        });
+
+        assert!(matches!(
+            cursors.kill("ip:10.0.0.1", "checked-out"),
+            Err(CursorError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn cursor_ids_are_random() {
+        let (first, second) = (new_cursor_id(), new_cursor_id());
+
+        assert_eq!(first.len(), 32);
+        assert!(first.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_ne!(first, second);
+    }
+
+    #[tokio::test]
+    async fn owner_prefers_verified_identity() {
+        let mut request = Request::new(());
+        request
+            .extensions_mut()
+            .insert(ConnectInfo(SocketAddr::from(([10, 0, 0, 1], 4000))));
+        assert_eq!(owner(request).await, "ip:10.0.0.1");
+
+        let mut request = Request::new(());
+        request.extensions_mut().insert(UnixPeer {
+            uid: 1000,
+            gid: 1000,
+        });
+        assert_eq!(owner(request).await, "unix:1000");
+
+        let mut request = Request::new(());
+        request.extensions_mut().insert(UnixPeer {
+            uid: 1000,
+            gid: 1000,
+        });
+        request.extensions_mut().insert(ClientIdentity {
+            subject: "CN=reporting".into(),
+        });
+        assert_eq!(owner(request).await, "tls:CN=reporting");
+    }
+}
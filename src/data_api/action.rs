@@ -0,0 +1,303 @@
+use crate::{
+    crud::{
+        aggregate::{self, AggregateBody},
+        delete_many::{self, DeleteManyBody},
+        delete_one::{self, DeleteOneBody},
+        find::{self, FindBody},
+        find_one::{self, FindOneBody},
+        insert_many::{self, InsertManyBody},
+        insert_one::{self, InsertOneBody},
+        replace_one::{self, ReplaceOneBody},
+        update_many::{self, UpdateManyBody},
+        update_one::{self, UpdateOneBody},
+    },
+    data_sources::{DataSource, DataSourceError, DataSources},
+    ejson::EJSON,
+};
+use axum::{
+    body::Body,
+    extract::{Path, State},
+    http::{header, HeaderMap, StatusCode},
+    response::Response,
+};
+use futures::stream::TryStreamExt;
+use mongodb::{
+    bson::{self, Document},
+    options::{FindOneOptions, FindOptions, ReplaceOptions, UpdateOptions},
+    results, Client,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    error::ActionError,
+    extract::ActionJson,
+    results::{
+        DeleteResult, DocumentsResult, FindOneResult, InsertManyResult, InsertOneResult,
+        UpdateResult,
+    },
+};
+
+/// The data source name Atlas clients send. It selects the data source
+/// configured under that name, or the default one.
+const ATLAS_DATA_SOURCE: &str = "mongodb-atlas";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionBody {
+    data_source: Option<String>,
+    database: String,
+    collection: String,
+    filter: Option<Document>,
+    projection: Option<Document>,
+    sort: Option<Document>,
+    limit: Option<i64>,
+    skip: Option<u64>,
+    pipeline: Option<Vec<Document>>,
+    document: Option<Document>,
+    documents: Option<Vec<Document>>,
+    update: Option<Document>,
+    replacement: Option<Document>,
+    upsert: Option<bool>,
+}
+
+pub async fn handler(
+    State(data_sources): State<DataSources>,
+    Path((_app_id, action)): Path<(String, String)>,
+    headers: HeaderMap,
+    ActionJson(args): ActionJson<ActionBody>,
+) -> Result<Response, ActionError> {
+    let client = data_source(&data_sources, args.data_source.as_deref())?;
+    let response = match action.as_str() {
+        "aggregate" => respond(&headers, StatusCode::OK, aggregate(client, args).await?),
+        "deleteMany" => respond(&headers, StatusCode::OK, delete_many(client, args).await?),
+        "deleteOne" => respond(&headers, StatusCode::OK, delete_one(client, args).await?),
+        "find" => respond(&headers, StatusCode::OK, find(client, args).await?),
+        "findOne" => respond(&headers, StatusCode::OK, find_one(client, args).await?),
+        "insertMany" => respond(
+            &headers,
+            StatusCode::CREATED,
+            insert_many(client, args).await?,
+        ),
+        "insertOne" => respond(
+            &headers,
+            StatusCode::CREATED,
+            insert_one(client, args).await?,
+        ),
+        "replaceOne" => respond(&headers, StatusCode::OK, replace_one(client, args).await?),
+        "updateMany" => respond(&headers, StatusCode::OK, update_many(client, args).await?),
+        "updateOne" => respond(&headers, StatusCode::OK, update_one(client, args).await?),
+        _ => return Err(ActionError::unknown_action(&action)),
+    };
+
+    Ok(response)
+}
+
+async fn aggregate(client: Client, args: ActionBody) -> Result<DocumentsResult, ActionError> {
+    let body = AggregateBody {
+        db: args.database,
+        collection: args.collection,
+        pipeline: required(args.pipeline, "pipeline")?,
+        options: None,
+        cursor: None,
+    };
+
+    let documents = aggregate::open_cursor(&client, body)
+        .await?
+        .try_collect()
+        .await?;
+
+    Ok(DocumentsResult { documents })
+}
+
+async fn delete_many(client: Client, args: ActionBody) -> Result<DeleteResult, ActionError> {
+    let body = DeleteManyBody {
+        db: args.database,
+        collection: args.collection,
+        filter: required(args.filter, "filter")?,
+        options: None,
+    };
+
+    let EJSON(result) = delete_many::handler(DataSource(client), EJSON(body)).await?;
+
+    Ok(DeleteResult {
+        deleted_count: result.deleted_count,
+    })
+}
+
+async fn delete_one(client: Client, args: ActionBody) -> Result<DeleteResult, ActionError> {
+    let body = DeleteOneBody {
+        db: args.database,
+        collection: args.collection,
+        filter: required(args.filter, "filter")?,
+        options: None,
+    };
+
+    let EJSON(result) = delete_one::handler(DataSource(client), EJSON(body)).await?;
+
+    Ok(DeleteResult {
+        deleted_count: result.deleted_count,
+    })
+}
+
+async fn find(client: Client, args: ActionBody) -> Result<DocumentsResult, ActionError> {
+    let options = FindOptions::builder()
+        .projection(args.projection)
+        .sort(args.sort)
+        .limit(args.limit)
+        .skip(args.skip)
+        .build();
+    let body = FindBody {
+        db: args.database,
+        collection: args.collection,
+        filter: args.filter.unwrap_or_default(),
+        options: Some(options),
+        cursor: None,
+    };
+
+    let documents = find::open_cursor(&client, body)
+        .await?
+        .try_collect()
+        .await?;
+
+    Ok(DocumentsResult { documents })
+}
+
+async fn find_one(client: Client, args: ActionBody) -> Result<FindOneResult, ActionError> {
+    let options = FindOneOptions::builder()
+        .projection(args.projection)
+        .sort(args.sort)
+        .build();
+    let body = FindOneBody {
+        db: args.database,
+        collection: args.collection,
+        filter: args.filter.unwrap_or_default(),
+        options: Some(options),
+    };
+
+    let EJSON(document) = find_one::handler(DataSource(client), EJSON(body)).await?;
+
+    Ok(FindOneResult { document })
+}
+
+async fn insert_many(client: Client, args: ActionBody) -> Result<InsertManyResult, ActionError> {
+    let body = InsertManyBody {
+        db: args.database,
+        collection: args.collection,
+        documents: required(args.documents, "documents")?,
+        options: None,
+    };
+
+    let EJSON(result) = insert_many::handler(DataSource(client), EJSON(body)).await?;
+
+    let mut inserted_ids: Vec<_> = result.inserted_ids.into_iter().collect();
+
+    inserted_ids.sort_by_key(|(index, _)| *index);
+
+    Ok(InsertManyResult {
+        inserted_ids: inserted_ids.into_iter().map(|(_, id)| id).collect(),
+    })
+}
+
+async fn insert_one(client: Client, args: ActionBody) -> Result<InsertOneResult, ActionError> {
+    let body = InsertOneBody {
+        db: args.database,
+        collection: args.collection,
+        document: required(args.document, "document")?,
+        options: None,
+    };
+
+    let EJSON(result) = insert_one::handler(DataSource(client), EJSON(body)).await?;
+
+    Ok(InsertOneResult {
+        inserted_id: result.inserted_id,
+    })
+}
+
+async fn replace_one(client: Client, args: ActionBody) -> Result<UpdateResult, ActionError> {
+    let body = ReplaceOneBody {
+        db: args.database,
+        collection: args.collection,
+        filter: required(args.filter, "filter")?,
+        replacement: required(args.replacement, "replacement")?,
+        options: Some(ReplaceOptions::builder().upsert(args.upsert).build()),
+    };
+
+    let EJSON(result) = replace_one::handler(DataSource(client), EJSON(body)).await?;
+
+    Ok(result.into())
+}
+
+async fn update_many(client: Client, args: ActionBody) -> Result<UpdateResult, ActionError> {
+    let body = UpdateManyBody {
+        db: args.database,
+        collection: args.collection,
+        query: required(args.filter, "filter")?,
+        update: required(args.update, "update")?,
+        options: Some(UpdateOptions::builder().upsert(args.upsert).build()),
+    };
+
+    let EJSON(result) = update_many::handler(DataSource(client), EJSON(body)).await?;
+
+    Ok(result.into())
+}
+
+async fn update_one(client: Client, args: ActionBody) -> Result<UpdateResult, ActionError> {
+    let body = UpdateOneBody {
+        db: args.database,
+        collection: args.collection,
+        query: required(args.filter, "filter")?,
+        update: required(args.update, "update")?,
+        options: Some(UpdateOptions::builder().upsert(args.upsert).build()),
+    };
+
+    let EJSON(result) = update_one::handler(DataSource(client), EJSON(body)).await?;
+
+    Ok(result.into())
+}
+
+impl From<results::UpdateResult> for UpdateResult {
+    fn from(result: results::UpdateResult) -> Self {
+        Self {
+            matched_count: result.matched_count,
+            modified_count: result.modified_count,
+            upserted_id: result.upserted_id,
+        }
+    }
+}
+
+fn data_source(data_sources: &DataSources, name: Option<&str>) -> Result<Client, ActionError> {
+    let client = match data_sources.get(name) {
+        Err(DataSourceError::Unknown(_)) if name == Some(ATLAS_DATA_SOURCE) => {
+            data_sources.get(None)
+        }
+        result => result,
+    }?;
+
+    Ok(client)
+}
+
+fn required<T>(value: Option<T>, field: &str) -> Result<T, ActionError> {
+    value.ok_or_else(|| ActionError::missing_parameter(field))
+}
+
+fn respond(headers: &HeaderMap, status: StatusCode, result: impl Serialize) -> Response {
+    let result = result.serialize(bson::Serializer::new()).unwrap();
+    let (content_type, body) = if accepts_ejson(headers) {
+        ("application/ejson", result.into_canonical_extjson())
+    } else {
+        ("application/json", result.into_relaxed_extjson())
+    };
+
+    Response::builder()
+        .status(status)
+        .header(header::CONTENT_TYPE, content_type)
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+fn accepts_ejson(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::ACCEPT)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.contains("application/ejson"))
+}
@@ -0,0 +1,146 @@
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use mongodb::error::{Error, ErrorKind};
+use serde_json::json;
+use std::fmt;
+
+use crate::{
+    crud::{replace_one::ReplaceOneError, validate::ValidationError},
+    data_sources::DataSourceError,
+    ejson::EJSON,
+};
+
+/// An error in the shape Atlas Data API clients expect:
+/// `{"error": ..., "error_code": ...}`.
+#[derive(Debug)]
+pub struct ActionError {
+    status: StatusCode,
+    code: &'static str,
+    message: String,
+}
+
+impl ActionError {
+    pub fn invalid_parameter(message: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::BAD_REQUEST,
+            code: "InvalidParameter",
+            message: message.into(),
+        }
+    }
+
+    pub fn missing_parameter(field: &str) -> Self {
+        Self {
+            status: StatusCode::BAD_REQUEST,
+            code: "MissingParameter",
+            message: format!("Missing required field: {field}"),
+        }
+    }
+
+    pub fn unknown_action(action: &str) -> Self {
+        Self {
+            status: StatusCode::NOT_FOUND,
+            code: "ActionNotFound",
+            message: format!("Unknown action: {action}"),
+        }
+    }
+}
+
+impl From<Error> for ActionError {
+    fn from(error: Error) -> Self {
+        let (status, code) = match *error.kind {
+            ErrorKind::InvalidArgument { .. } => (StatusCode::BAD_REQUEST, "InvalidParameter"),
+            ErrorKind::Command(_)
+            | ErrorKind::Write(_)
+            | ErrorKind::InsertMany(_)
+            | ErrorKind::BulkWrite(_) => (StatusCode::BAD_REQUEST, "FunctionExecutionError"),
+            _ => (StatusCode::INTERNAL_SERVER_ERROR, "InternalServerError"),
+        };
+
+        Self {
+            status,
+            code,
+            message: error.to_string(),
+        }
+    }
+}
+
+impl From<EJSON<Error>> for ActionError {
+    fn from(EJSON(error): EJSON<Error>) -> Self {
+        error.into()
+    }
+}
+
+impl From<ValidationError> for ActionError {
+    fn from(error: ValidationError) -> Self {
+        Self::invalid_parameter(error.message())
+    }
+}
+
+impl From<ReplaceOneError> for ActionError {
+    fn from(error: ReplaceOneError) -> Self {
+        match error {
+            ReplaceOneError::Invalid(error) => error.into(),
+            ReplaceOneError::Driver(error) => error.into(),
+        }
+    }
+}
+
+impl From<DataSourceError> for ActionError {
+    fn from(error: DataSourceError) -> Self {
+        match error {
+            DataSourceError::Unknown(name) => {
+                Self::invalid_parameter(format!("Unknown data source: {name}"))
+            }
+        }
+    }
+}
+
+impl fmt::Display for ActionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.code, self.message)
+    }
+}
+
+impl std::error::Error for ActionError {}
+
+impl IntoResponse for ActionError {
+    fn into_response(self) -> Response {
+        (
+            self.status,
+            Json(json!({"error": self.message, "error_code": self.code})),
+        )
+            .into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::to_bytes;
+    use serde_json::Value;
+
+    #[tokio::test]
+    async fn renders_atlas_shape() {
+        let response = ActionError::missing_parameter("update").into_response();
+        let (parts, body) = response.into_parts();
+        let body = to_bytes(body, usize::MAX).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+        assert_eq!(
+            body,
+            json!({"error": "Missing required field: update", "error_code": "MissingParameter"})
+        );
+    }
+
+    #[test]
+    fn maps_driver_errors() {
+        let error = ActionError::from(Error::custom("boom"));
+
+        assert_eq!(error.status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(error.code, "InternalServerError");
+    }
+}
@@ -0,0 +1,51 @@
+use axum::{
+    async_trait,
+    body::Bytes,
+    extract::{FromRequest, Request},
+    http::header,
+};
+use mongodb::bson::{self, Bson};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use super::error::ActionError;
+
+const ACCEPTED_CONTENT_TYPES: [&str; 2] = ["application/json", "application/ejson"];
+
+/// A Data API request body. Atlas clients send plain JSON as well as EJSON,
+/// so unlike `EJSON` this accepts both content types.
+pub struct ActionJson<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequest<S> for ActionJson<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned,
+{
+    type Rejection = ActionError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let media_type = req
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(';').next())
+            .map(str::trim);
+
+        if !media_type.is_some_and(|media_type| ACCEPTED_CONTENT_TYPES.contains(&media_type)) {
+            return Err(ActionError::invalid_parameter("Content Type not accepted"));
+        }
+
+        let bytes = Bytes::from_request(req, state)
+            .await
+            .map_err(|error| ActionError::invalid_parameter(error.body_text()))?;
+        let value: Value = serde_json::from_slice(&bytes)
+            .map_err(|error| ActionError::invalid_parameter(error.to_string()))?;
+        let bson = Bson::try_from(value)
+            .map_err(|_| ActionError::invalid_parameter("JSON to BSON parse error"))?;
+        let body = T::deserialize(bson::Deserializer::new(bson))
+            .map_err(|error| ActionError::invalid_parameter(error.to_string()))?;
+
+        Ok(ActionJson(body))
+    }
+}
@@ -0,0 +1,4 @@
+pub mod action;
+pub mod error;
+pub mod extract;
+pub mod results;
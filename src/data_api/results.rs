@@ -0,0 +1,39 @@
+use mongodb::bson::{Bson, Document};
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct FindOneResult {
+    pub document: Option<Document>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DocumentsResult {
+    pub documents: Vec<Document>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InsertOneResult {
+    pub inserted_id: Bson,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InsertManyResult {
+    pub inserted_ids: Vec<Bson>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateResult {
+    pub matched_count: u64,
+    pub modified_count: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upserted_id: Option<Bson>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteResult {
+    pub deleted_count: u64,
+}
@@ -0,0 +1,142 @@
+use axum::{
+    async_trait,
+    body::Body,
+    extract::{FromRef, FromRequestParts, Query, Request},
+    http::{request::Parts, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use mongodb::{bson::Bson, Client};
+use serde::Deserialize;
+use serde_json::json;
+
+use super::DataSources;
+use crate::ejson::from_request::{read_bson, ParsedBody};
+
+pub struct DataSource(pub Client);
+
+#[derive(Clone)]
+struct DataSourceName(String);
+
+#[derive(Deserialize)]
+struct DataSourceField {
+    #[serde(rename = "dataSource")]
+    data_source: Option<String>,
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for DataSource
+where
+    DataSources: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let name = match parts.extensions.get::<DataSourceName>() {
+            Some(DataSourceName(name)) => Some(name.clone()),
+            None => Query::<DataSourceField>::try_from_uri(&parts.uri)
+                .ok()
+                .and_then(|Query(field)| field.data_source),
+        };
+
+        DataSources::from_ref(state)
+            .get(name.as_deref())
+            .map(DataSource)
+            .map_err(IntoResponse::into_response)
+    }
+}
+
+/// Reads `dataSource` from the body of the EJSON endpoints. The parsed body is
+/// handed on to the `EJSON` extractor.
+pub async fn read_body_data_source(request: Request, next: Next) -> Response {
+    let (mut parts, body) = request.into_parts();
+    let body_bson = match read_bson(Request::from_parts(parts.clone(), body), &()).await {
+        Ok(body_bson) => body_bson,
+        Err(response) => return response,
+    };
+
+    match body_bson
+        .as_document()
+        .and_then(|body| body.get("dataSource"))
+    {
+        Some(Bson::String(name)) => {
+            parts.extensions.insert(DataSourceName(name.clone()));
+        }
+        Some(Bson::Null) | None => {}
+        Some(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({"message": "dataSource must be a string"})),
+            )
+                .into_response()
+        }
+    }
+
+    parts.extensions.insert(ParsedBody(body_bson));
+
+    next.run(Request::from_parts(parts, Body::empty())).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ejson::EJSON;
+    use axum::{body::to_bytes, http::header, middleware, routing::post, Extension, Router};
+    use mongodb::bson::{doc, Document};
+    use tower::ServiceExt;
+
+    async fn echo(name: Option<Extension<DataSourceName>>, EJSON(body): EJSON<Document>) -> String {
+        let name = name.map(|Extension(DataSourceName(name))| name);
+
+        format!("{name:?} {body}")
+    }
+
+    async fn send(body: &str) -> (StatusCode, String) {
+        let request = Request::builder()
+            .method("POST")
+            .uri("/")
+            .header(header::CONTENT_TYPE, "application/ejson")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let response = Router::new()
+            .route("/", post(echo))
+            .route_layer(middleware::from_fn(read_body_data_source))
+            .oneshot(request)
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn reads_data_source_and_passes_body_on() {
+        let (status, body) = send(r#"{"dataSource": "archive", "db": "test"}"#).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            body,
+            format!(
+                "{:?} {}",
+                Some("archive"),
+                doc! {"dataSource": "archive", "db": "test"}
+            )
+        );
+
+        let (status, body) = send(r#"{"dataSource": null, "db": "test"}"#).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.starts_with("None "));
+    }
+
+    #[tokio::test]
+    async fn rejects_non_string_data_source() {
+        let (status, body) = send(r#"{"dataSource": 5, "db": "test"}"#).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body, r#"{"message":"dataSource must be a string"}"#);
+    }
+}
@@ -0,0 +1,145 @@
+pub mod extract;
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use mongodb::Client;
+use serde_json::json;
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+
+use crate::{
+    app::StartupError,
+    config::Config,
+    mdb::{self, Topology},
+};
+
+pub use extract::{read_body_data_source, DataSource};
+
+#[derive(Clone)]
+pub struct DataSources {
+    clusters: Arc<BTreeMap<String, Cluster>>,
+    default: String,
+}
+
+#[derive(Clone)]
+pub struct Cluster {
+    pub client: Client,
+    pub topology: Topology,
+}
+
+#[derive(Debug)]
+pub enum DataSourceError {
+    Unknown(String),
+}
+
+impl DataSources {
+    /// Clients passed in directly are not tracked for topology changes, so
+    /// readiness checks rely on the ping alone.
+    pub fn new(clients: HashMap<String, Client>, default: String) -> Self {
+        let clusters = clients
+            .into_iter()
+            .map(|(name, client)| {
+                let topology = Topology::default();
+
+                (name, Cluster { client, topology })
+            })
+            .collect();
+
+        Self {
+            clusters: Arc::new(clusters),
+            default,
+        }
+    }
+
+    pub async fn connect(config: &Config) -> Result<Self, StartupError> {
+        Self::connect_reusing(config, None).await
+    }
+
+    /// Builds the data sources for a reloaded config, keeping the existing
+    /// client for every data source whose settings did not change.
+    pub async fn reload(&self, current: &Config, config: &Config) -> Result<Self, StartupError> {
+        Self::connect_reusing(config, Some((self, current))).await
+    }
+
+    async fn connect_reusing(
+        config: &Config,
+        previous: Option<(&DataSources, &Config)>,
+    ) -> Result<Self, StartupError> {
+        let mut clusters = BTreeMap::new();
+
+        for (name, data_source) in &config.data_sources {
+            let reusable = previous
+                .filter(|(_, current)| current.data_sources.get(name) == Some(data_source))
+                .and_then(|(data_sources, _)| data_sources.clusters.get(name).cloned());
+
+            if let Some(cluster) = reusable {
+                clusters.insert(name.clone(), cluster);
+                continue;
+            }
+
+            let topology = Topology::default();
+            let client = match previous {
+                Some(_) => mdb::reconnect(data_source, &topology).await,
+                None => mdb::connect(data_source, &topology).await,
+            }
+            .map_err(|source| StartupError::Connect {
+                data_source: name.clone(),
+                source,
+            })?;
+
+            clusters.insert(name.clone(), Cluster { client, topology });
+        }
+
+        Ok(Self {
+            clusters: Arc::new(clusters),
+            default: config.default_data_source.clone(),
+        })
+    }
+
+    /// The clients a `reload` from `current` to `config` does not carry over.
+    pub fn retired(&self, current: &Config, config: &Config) -> Vec<Client> {
+        self.clusters
+            .iter()
+            .filter(|(name, _)| config.data_sources.get(*name) != current.data_sources.get(*name))
+            .map(|(_, cluster)| cluster.client.clone())
+            .collect()
+    }
+
+    pub fn clusters(&self) -> impl Iterator<Item = (&String, &Cluster)> {
+        self.clusters.iter()
+    }
+
+    /// Waits for the driver to finish background cleanup (such as killing
+    /// cursors that were just dropped) and closes every connection pool.
+    pub async fn shutdown(&self) {
+        for cluster in self.clusters.values() {
+            cluster.client.clone().shutdown().await;
+        }
+    }
+
+    pub fn get(&self, name: Option<&str>) -> Result<Client, DataSourceError> {
+        let name = name.unwrap_or(&self.default);
+
+        self.clusters
+            .get(name)
+            .map(|cluster| cluster.client.clone())
+            .ok_or_else(|| DataSourceError::Unknown(name.to_string()))
+    }
+}
+
+impl IntoResponse for DataSourceError {
+    fn into_response(self) -> Response {
+        match self {
+            DataSourceError::Unknown(name) => (
+                StatusCode::BAD_REQUEST,
+                Json(json!({"message": format!("Unknown data source: {name}")})),
+            )
+                .into_response(),
+        }
+    }
+}
@@ -21,22 +21,38 @@ where
 {
     type Rejection = Response;
 
-    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
-        let header_value = get_header_value(&req)?;
-        let content_type = get_content_type(header_value)?;
-
-        validate_content_type(content_type)?;
-
-        let body_bytes = get_body_as_bytes(req, state).await?;
-        let body_json = bytes_to_json(body_bytes)?;
-        let body_bson = json_to_bson(body_json)?;
+    async fn from_request(mut req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let body_bson = match req.extensions_mut().remove::<ParsedBody>() {
+            Some(ParsedBody(body_bson)) => body_bson,
+            None => read_bson(req, state).await?,
+        };
         let body_struct = bson_to_struct::<T>(body_bson)?;
 
         Ok(EJSON(body_struct))
     }
 }
 
-fn get_header_value<'a>(req: &'a Request) -> Result<&'a HeaderValue, Response<Body>> {
+/// A request body already read by a middleware, so `EJSON` does not parse it
+/// a second time.
+#[derive(Clone)]
+pub(crate) struct ParsedBody(pub(crate) Bson);
+
+pub(crate) async fn read_bson<S: Send + Sync>(
+    req: Request,
+    state: &S,
+) -> Result<Bson, Response<Body>> {
+    let header_value = get_header_value(&req)?;
+    let content_type = get_content_type(header_value)?;
+
+    validate_content_type(content_type)?;
+
+    let body_bytes = get_body_as_bytes(req, state).await?;
+    let body_json = bytes_to_json(body_bytes)?;
+
+    json_to_bson(body_json)
+}
+
+fn get_header_value(req: &Request) -> Result<&HeaderValue, Response<Body>> {
     let header_value = req.headers().get(header::CONTENT_TYPE).ok_or(
         (
             StatusCode::BAD_REQUEST,
@@ -48,7 +64,7 @@ fn get_header_value<'a>(req: &'a Request) -> Result<&'a HeaderValue, Response<Bo
     Ok(header_value)
 }
 
-fn get_content_type<'a>(header_value: &'a HeaderValue) -> Result<&'a str, Response<Body>> {
+fn get_content_type(header_value: &HeaderValue) -> Result<&str, Response<Body>> {
     let content_type = header_value.to_str().map_err(|e| {
         (
             StatusCode::BAD_REQUEST,
@@ -135,7 +151,7 @@ mod tests {
 
     async fn body_to_json(body: Body) -> Json<Value> {
         let body_bytes = to_bytes(body, usize::MAX).await.unwrap();
-        let body_json: Json<Value> = Json::from_bytes(&*body_bytes).unwrap();
+        let body_json: Json<Value> = Json::from_bytes(&body_bytes).unwrap();
 
         body_json
     }
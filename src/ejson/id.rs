@@ -0,0 +1,74 @@
+use mongodb::bson::{oid::ObjectId, Bson};
+use serde_json::Value;
+
+/// Reads a document id from a URL. A 24 character hex string is an ObjectId
+/// and an EJSON object such as `{"$numberInt":"7"}` is its typed value;
+/// anything else, including `123` or `null`, is a string.
+pub fn parse(id: &str) -> Bson {
+    if let Ok(object_id) = id.parse::<ObjectId>() {
+        return Bson::ObjectId(object_id);
+    }
+
+    match serde_json::from_str::<Value>(id) {
+        Ok(value @ Value::Object(_)) => {
+            Bson::try_from(value).unwrap_or_else(|_| Bson::String(id.to_string()))
+        }
+        _ => Bson::String(id.to_string()),
+    }
+}
+
+/// The inverse of `parse`, for building URLs to documents.
+pub fn format(id: &Bson) -> String {
+    match id {
+        Bson::ObjectId(object_id) => object_id.to_hex(),
+        Bson::String(id) if parse(id) == Bson::String(id.clone()) => id.clone(),
+        id => id.clone().into_canonical_extjson().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mongodb::bson::doc;
+
+    #[test]
+    fn parses_object_id() {
+        let object_id = ObjectId::new();
+
+        assert_eq!(parse(&object_id.to_hex()), Bson::ObjectId(object_id));
+    }
+
+    #[test]
+    fn parses_ejson_id() {
+        assert_eq!(parse(r#"{"$numberInt":"7"}"#), Bson::Int32(7));
+        assert_eq!(
+            parse(r#"{"key":"value"}"#),
+            Bson::Document(doc! {"key": "value"})
+        );
+    }
+
+    #[test]
+    fn falls_back_to_string_id() {
+        assert_eq!(parse("avatar.png"), Bson::String("avatar.png".into()));
+        assert_eq!(parse("123"), Bson::String("123".into()));
+        assert_eq!(parse("null"), Bson::String("null".into()));
+        assert_eq!(parse("true"), Bson::String("true".into()));
+        assert_eq!(parse(r#""quoted""#), Bson::String(r#""quoted""#.into()));
+    }
+
+    #[test]
+    fn formats_parseable_ids() {
+        let object_id = ObjectId::new();
+
+        for id in [
+            Bson::ObjectId(object_id),
+            Bson::String("123".into()),
+            Bson::Int32(7),
+            Bson::Document(doc! {"key": "value"}),
+        ] {
+            assert_eq!(parse(&format(&id)), id);
+        }
+
+        assert_eq!(format(&Bson::Int32(7)), r#"{"$numberInt":"7"}"#);
+    }
+}
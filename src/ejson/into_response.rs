@@ -1,18 +1,31 @@
 use axum::{
     body::Body,
     http::{header, StatusCode},
-    response::{IntoResponse, Response},
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Response,
+    },
 };
+use futures::stream::{StreamExt, TryStreamExt};
 use mongodb::{
     self,
     bson::{self, Bson, Document},
-    error::ErrorKind,
+    error::{ErrorKind, GridFsErrorKind},
     results::{DeleteResult, InsertManyResult, InsertOneResult, UpdateResult},
+    Cursor, IndexModel,
 };
 use serde::Serialize;
 use serde_json::json;
+use std::convert::Infallible;
 
 use super::EJSON;
+use crate::{
+    admin::results::ValidationRules,
+    change_stream::Watch,
+    crud::results::{BulkWriteFailure, BulkWriteResult, CountResult, TransactionResult},
+    cursors::{kill_cursors::KillCursorsResult, CursorBatch},
+    indexes::results::CreateIndexesResult,
+};
 
 fn struct_to_ejson_string(structure: impl Serialize) -> String {
     structure
@@ -22,6 +35,19 @@ fn struct_to_ejson_string(structure: impl Serialize) -> String {
         .to_string()
 }
 
+impl IntoResponse for EJSON<Document> {
+    fn into_response(self) -> Response {
+        let body_bson: Bson = self.0.into();
+        let body_ejson_string = body_bson.into_canonical_extjson().to_string();
+
+        Response::builder()
+            .status(StatusCode::ACCEPTED)
+            .header(header::CONTENT_TYPE, "application/ejson")
+            .body(Body::from(body_ejson_string))
+            .unwrap()
+    }
+}
+
 impl IntoResponse for EJSON<Option<Document>> {
     fn into_response(self) -> Response {
         let body_bson: Bson = self.0.into();
@@ -48,6 +74,36 @@ impl IntoResponse for EJSON<Vec<Document>> {
     }
 }
 
+impl IntoResponse for EJSON<Vec<Bson>> {
+    fn into_response(self) -> Response {
+        let body_bson: Bson = self.0.into();
+        let body_ejson_string = body_bson.into_canonical_extjson().to_string();
+
+        Response::builder()
+            .status(StatusCode::ACCEPTED)
+            .header(header::CONTENT_TYPE, "application/ejson")
+            .body(Body::from(body_ejson_string))
+            .unwrap()
+    }
+}
+
+impl IntoResponse for EJSON<Cursor<Document>> {
+    fn into_response(self) -> Response {
+        let lines = self.0.map_ok(|document| {
+            let mut line = Bson::from(document).into_canonical_extjson().to_string();
+
+            line.push('\n');
+            line
+        });
+
+        Response::builder()
+            .status(StatusCode::ACCEPTED)
+            .header(header::CONTENT_TYPE, "application/x-ndjson")
+            .body(Body::from_stream(lines))
+            .unwrap()
+    }
+}
+
 impl IntoResponse for EJSON<InsertOneResult> {
     fn into_response(self) -> Response {
         let body_ejson_string = struct_to_ejson_string(self.0);
@@ -96,17 +152,162 @@ impl IntoResponse for EJSON<DeleteResult> {
     }
 }
 
+impl IntoResponse for EJSON<CountResult> {
+    fn into_response(self) -> Response {
+        let body_ejson_string = struct_to_ejson_string(self.0);
+
+        Response::builder()
+            .status(StatusCode::ACCEPTED)
+            .header(header::CONTENT_TYPE, "application/ejson")
+            .body(Body::from(body_ejson_string))
+            .unwrap()
+    }
+}
+
+impl IntoResponse for EJSON<BulkWriteResult> {
+    fn into_response(self) -> Response {
+        let body_ejson_string = struct_to_ejson_string(self.0);
+
+        Response::builder()
+            .status(StatusCode::ACCEPTED)
+            .header(header::CONTENT_TYPE, "application/ejson")
+            .body(Body::from(body_ejson_string))
+            .unwrap()
+    }
+}
+
+impl IntoResponse for EJSON<()> {
+    fn into_response(self) -> Response {
+        Response::builder()
+            .status(StatusCode::ACCEPTED)
+            .header(header::CONTENT_TYPE, "application/ejson")
+            .body(Body::from("{}"))
+            .unwrap()
+    }
+}
+
+impl IntoResponse for EJSON<Vec<IndexModel>> {
+    fn into_response(self) -> Response {
+        let body_ejson_string = struct_to_ejson_string(self.0);
+
+        Response::builder()
+            .status(StatusCode::ACCEPTED)
+            .header(header::CONTENT_TYPE, "application/ejson")
+            .body(Body::from(body_ejson_string))
+            .unwrap()
+    }
+}
+
+impl IntoResponse for EJSON<CreateIndexesResult> {
+    fn into_response(self) -> Response {
+        let body_ejson_string = struct_to_ejson_string(self.0);
+
+        Response::builder()
+            .status(StatusCode::ACCEPTED)
+            .header(header::CONTENT_TYPE, "application/ejson")
+            .body(Body::from(body_ejson_string))
+            .unwrap()
+    }
+}
+
+impl IntoResponse for EJSON<ValidationRules> {
+    fn into_response(self) -> Response {
+        let body_ejson_string = struct_to_ejson_string(self.0);
+
+        Response::builder()
+            .status(StatusCode::ACCEPTED)
+            .header(header::CONTENT_TYPE, "application/ejson")
+            .body(Body::from(body_ejson_string))
+            .unwrap()
+    }
+}
+
+impl IntoResponse for EJSON<TransactionResult> {
+    fn into_response(self) -> Response {
+        let body_ejson_string = struct_to_ejson_string(self.0);
+
+        Response::builder()
+            .status(StatusCode::ACCEPTED)
+            .header(header::CONTENT_TYPE, "application/ejson")
+            .body(Body::from(body_ejson_string))
+            .unwrap()
+    }
+}
+
+impl IntoResponse for EJSON<CursorBatch> {
+    fn into_response(self) -> Response {
+        let body_ejson_string = struct_to_ejson_string(self.0);
+
+        Response::builder()
+            .status(StatusCode::ACCEPTED)
+            .header(header::CONTENT_TYPE, "application/ejson")
+            .body(Body::from(body_ejson_string))
+            .unwrap()
+    }
+}
+
+impl IntoResponse for EJSON<KillCursorsResult> {
+    fn into_response(self) -> Response {
+        let body_ejson_string = struct_to_ejson_string(self.0);
+
+        Response::builder()
+            .status(StatusCode::ACCEPTED)
+            .header(header::CONTENT_TYPE, "application/ejson")
+            .body(Body::from(body_ejson_string))
+            .unwrap()
+    }
+}
+
+impl IntoResponse for EJSON<Watch> {
+    fn into_response(self) -> Response {
+        let events = self.0.map(|result| {
+            let event = match result {
+                Ok(change) => Event::default().data(struct_to_ejson_string(change)),
+                Err(error) => Event::default()
+                    .event("error")
+                    .data(json!({"message": error.to_string()}).to_string()),
+            };
+
+            Ok::<Event, Infallible>(event)
+        });
+
+        Sse::new(events)
+            .keep_alive(KeepAlive::default())
+            .into_response()
+    }
+}
+
 impl IntoResponse for EJSON<mongodb::error::Error> {
     fn into_response(self) -> Response {
+        let status = match *self.0.kind {
+            ErrorKind::GridFs(GridFsErrorKind::FileNotFound { .. }) => StatusCode::NOT_FOUND,
+            _ => StatusCode::BAD_REQUEST,
+        };
+
+        let err_info = self
+            .0
+            .server_response()
+            .and_then(|response| response.get_document("errInfo").ok())
+            .and_then(|err_info| Document::try_from(err_info).ok());
+
         let data = match *self.0.kind {
             ErrorKind::InsertMany(e) => struct_to_ejson_string(e),
+            ErrorKind::BulkWrite(e) => struct_to_ejson_string(BulkWriteFailure::from(e)),
             ErrorKind::Write(e) => struct_to_ejson_string(e),
-            ErrorKind::Command(e) => struct_to_ejson_string(e),
+            ErrorKind::Command(e) => {
+                let mut document = bson::to_document(&e).unwrap();
+
+                if let Some(err_info) = err_info {
+                    document.insert("errInfo", err_info);
+                }
+
+                Bson::from(document).into_canonical_extjson().to_string()
+            }
             e => json!({"message": e.to_string()}).to_string(),
         };
 
         Response::builder()
-            .status(StatusCode::BAD_REQUEST)
+            .status(status)
             .header(header::CONTENT_TYPE, "application/ejson")
             .body(Body::from(data))
             .unwrap()
@@ -1,4 +1,5 @@
 pub mod from_request;
+pub mod id;
 pub mod into_response;
 
 pub struct EJSON<T>(pub T);
@@ -0,0 +1,22 @@
+use crate::{data_sources::DataSource, ejson::EJSON};
+use mongodb::bson::Bson;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct DeleteFileBody {
+    db: String,
+    bucket: Option<String>,
+    id: Bson,
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    EJSON(args): EJSON<DeleteFileBody>,
+) -> Result<EJSON<()>, EJSON<mongodb::error::Error>> {
+    super::bucket(&client, &args.db, args.bucket)
+        .delete(args.id)
+        .await
+        .map_err(EJSON)?;
+
+    Ok(EJSON(()))
+}
@@ -0,0 +1,113 @@
+use crate::{
+    data_sources::DataSource,
+    ejson::{id, EJSON},
+};
+use axum::{
+    body::Body,
+    extract::Query,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use futures::{stream, AsyncReadExt};
+use mongodb::{
+    bson::doc,
+    gridfs::{FilesCollectionDocument, GridFsDownloadStream},
+    options::GridFsFindOneOptions,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+use super::DEFAULT_CONTENT_TYPE;
+
+const READ_BUFFER_SIZE: usize = 64 * 1024;
+
+#[derive(Debug, Deserialize)]
+pub struct DownloadQuery {
+    db: String,
+    bucket: Option<String>,
+    id: Option<String>,
+    filename: Option<String>,
+}
+
+pub struct FileDownload {
+    file: FilesCollectionDocument,
+    stream: GridFsDownloadStream,
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    Query(query): Query<DownloadQuery>,
+) -> Result<FileDownload, Response> {
+    let bucket = super::bucket(&client, &query.db, query.bucket);
+    let (filter, options) = match (query.id, query.filename) {
+        (Some(id), None) => (doc! {"_id": id::parse(&id)}, None),
+        (None, Some(filename)) => (
+            doc! {"filename": filename},
+            Some(
+                GridFsFindOneOptions::builder()
+                    .sort(doc! {"uploadDate": -1})
+                    .build(),
+            ),
+        ),
+        _ => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(json!({"message": "Exactly one of id or filename is required"})),
+            )
+                .into_response())
+        }
+    };
+
+    let file = bucket
+        .find_one(filter)
+        .with_options(options)
+        .await
+        .map_err(|error| EJSON(error).into_response())?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(json!({"message": "File not found"})),
+            )
+                .into_response()
+        })?;
+
+    let stream = bucket
+        .open_download_stream(file.id.clone())
+        .await
+        .map_err(|error| EJSON(error).into_response())?;
+
+    Ok(FileDownload { file, stream })
+}
+
+impl IntoResponse for FileDownload {
+    fn into_response(self) -> Response {
+        let content_type = self
+            .file
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get_str("contentType").ok())
+            .unwrap_or(DEFAULT_CONTENT_TYPE)
+            .to_string();
+
+        let chunks = stream::try_unfold(self.stream, |mut stream| async move {
+            let mut buffer = vec![0; READ_BUFFER_SIZE];
+            let read = stream.read(&mut buffer).await?;
+
+            if read == 0 {
+                return Ok::<_, std::io::Error>(None);
+            }
+
+            buffer.truncate(read);
+
+            Ok(Some((buffer, stream)))
+        });
+
+        Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, content_type)
+            .header(header::CONTENT_LENGTH, self.file.length)
+            .body(Body::from_stream(chunks))
+            .unwrap()
+    }
+}
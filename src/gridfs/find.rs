@@ -0,0 +1,58 @@
+use crate::{data_sources::DataSource, ejson::EJSON};
+use futures::TryStreamExt;
+use mongodb::{
+    bson::{self, Document},
+    options::GridFsFindOptions,
+};
+use serde::Deserialize;
+use std::time::Duration;
+
+#[derive(Debug, Deserialize)]
+pub struct FindFilesBody {
+    db: String,
+    bucket: Option<String>,
+    filter: Document,
+    options: Option<FindFilesOptions>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FindFilesOptions {
+    allow_disk_use: Option<bool>,
+    batch_size: Option<u32>,
+    limit: Option<i64>,
+    #[serde(rename = "maxTimeMS")]
+    max_time_ms: Option<u64>,
+    skip: Option<u64>,
+    sort: Option<Document>,
+}
+
+impl From<FindFilesOptions> for GridFsFindOptions {
+    fn from(options: FindFilesOptions) -> Self {
+        GridFsFindOptions::builder()
+            .allow_disk_use(options.allow_disk_use)
+            .batch_size(options.batch_size)
+            .limit(options.limit)
+            .max_time(options.max_time_ms.map(Duration::from_millis))
+            .skip(options.skip)
+            .sort(options.sort)
+            .build()
+    }
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    EJSON(args): EJSON<FindFilesBody>,
+) -> Result<EJSON<Vec<Document>>, EJSON<mongodb::error::Error>> {
+    let files = super::bucket(&client, &args.db, args.bucket)
+        .find(args.filter)
+        .with_options(args.options.map(GridFsFindOptions::from))
+        .await
+        .map_err(EJSON)?
+        .and_then(|file| async move { Ok(bson::to_document(&file)?) })
+        .try_collect()
+        .await
+        .map_err(EJSON)?;
+
+    Ok(EJSON(files))
+}
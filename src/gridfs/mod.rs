@@ -0,0 +1,14 @@
+pub mod delete;
+pub mod download;
+pub mod find;
+pub mod upload;
+
+use mongodb::{gridfs::GridFsBucket, options::GridFsBucketOptions, Client};
+
+pub const DEFAULT_CONTENT_TYPE: &str = "application/octet-stream";
+
+pub(crate) fn bucket(client: &Client, db: &str, bucket: Option<String>) -> GridFsBucket {
+    let options = GridFsBucketOptions::builder().bucket_name(bucket).build();
+
+    client.database(db).gridfs_bucket(options)
+}
@@ -0,0 +1,153 @@
+use crate::{
+    data_sources::DataSource,
+    ejson::{id, EJSON},
+    rest,
+};
+use axum::{
+    body::Bytes,
+    extract::{FromRequest, Multipart, Query, Request},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use futures::{AsyncWriteExt, Stream, TryStreamExt};
+use mongodb::{
+    bson::{doc, Bson, Document},
+    gridfs::GridFsBucket,
+    options::GridFsUploadOptions,
+};
+use serde::Deserialize;
+use serde_json::json;
+use std::{fmt::Display, pin::pin};
+
+use super::DEFAULT_CONTENT_TYPE;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadQuery {
+    db: String,
+    bucket: Option<String>,
+    filename: Option<String>,
+    id: Option<String>,
+    metadata: Option<String>,
+    chunk_size_bytes: Option<u32>,
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    Query(query): Query<UploadQuery>,
+    request: Request,
+) -> Result<EJSON<Document>, Response> {
+    let metadata = rest::query_document(query.metadata.as_deref(), "metadata")?.unwrap_or_default();
+    let content_type = request
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or(DEFAULT_CONTENT_TYPE)
+        .to_string();
+    let bucket = super::bucket(&client, &query.db, query.bucket);
+
+    if !content_type.starts_with("multipart/") {
+        let filename = query
+            .filename
+            .ok_or_else(|| bad_request("Missing query parameter filename".into()))?;
+        let options = upload_options(metadata, content_type, query.chunk_size_bytes);
+        let id = query.id.as_deref().map(id::parse);
+        let chunks = request.into_body().into_data_stream();
+        let id = upload(&bucket, &filename, id, options, chunks).await?;
+
+        return Ok(EJSON(doc! {"id": id}));
+    }
+
+    if query.id.is_some() {
+        return Err(bad_request(
+            "Query parameter id is not supported for multipart uploads".into(),
+        ));
+    }
+
+    let mut multipart = Multipart::from_request(request, &())
+        .await
+        .map_err(IntoResponse::into_response)?;
+    let mut ids = Vec::new();
+
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(IntoResponse::into_response)?
+    {
+        let Some(filename) = field.file_name().map(str::to_string) else {
+            continue;
+        };
+        let content_type = field.content_type().unwrap_or(DEFAULT_CONTENT_TYPE);
+        let options = upload_options(
+            metadata.clone(),
+            content_type.to_string(),
+            query.chunk_size_bytes,
+        );
+
+        ids.push(upload(&bucket, &filename, None, options, field).await?);
+    }
+
+    Ok(EJSON(doc! {"ids": ids}))
+}
+
+fn upload_options(
+    mut metadata: Document,
+    content_type: String,
+    chunk_size_bytes: Option<u32>,
+) -> GridFsUploadOptions {
+    metadata.insert("contentType", content_type);
+
+    GridFsUploadOptions::builder()
+        .chunk_size_bytes(chunk_size_bytes)
+        .metadata(metadata)
+        .build()
+}
+
+async fn upload<E: Display>(
+    bucket: &GridFsBucket,
+    filename: &str,
+    id: Option<Bson>,
+    options: GridFsUploadOptions,
+    chunks: impl Stream<Item = Result<Bytes, E>>,
+) -> Result<Bson, Response> {
+    let mut open_upload_stream = bucket.open_upload_stream(filename).with_options(options);
+
+    if let Some(id) = id {
+        open_upload_stream = open_upload_stream.id(id);
+    }
+
+    let mut upload = open_upload_stream
+        .await
+        .map_err(|error| EJSON(error).into_response())?;
+    let mut chunks = pin!(chunks);
+
+    loop {
+        let chunk = match chunks.try_next().await {
+            Ok(Some(chunk)) => chunk,
+            Ok(None) => break,
+            Err(error) => {
+                let _ = upload.abort().await;
+
+                return Err(bad_request(format!("Failed to read upload body: {error}")));
+            }
+        };
+
+        if let Err(error) = upload.write_all(&chunk).await {
+            let _ = upload.abort().await;
+
+            return Err(EJSON(mongodb::error::Error::from(error)).into_response());
+        }
+    }
+
+    upload
+        .close()
+        .await
+        .map_err(|error| EJSON(mongodb::error::Error::from(error)).into_response())?;
+
+    Ok(upload.id().clone())
+}
+
+fn bad_request(message: String) -> Response {
+    (StatusCode::BAD_REQUEST, Json(json!({"message": message}))).into_response()
+}
@@ -0,0 +1,104 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use futures::future::join_all;
+use mongodb::bson::doc;
+use serde::Serialize;
+use std::{
+    collections::BTreeMap,
+    time::{Duration, Instant},
+};
+
+use crate::data_sources::{Cluster, DataSources};
+
+const PING_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadyResult {
+    pub status: &'static str,
+    pub data_sources: BTreeMap<String, DataSourceStatus>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataSourceStatus {
+    pub status: &'static str,
+    pub topology_type: String,
+    pub selectable: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+pub async fn handler(State(data_sources): State<DataSources>) -> Response {
+    let checks = data_sources
+        .clusters()
+        .map(|(name, cluster)| async move { (name.clone(), check(cluster).await) });
+    let data_sources: BTreeMap<_, _> = join_all(checks).await.into_iter().collect();
+
+    let (status_code, status) = if data_sources.values().all(|source| source.selectable) {
+        (StatusCode::OK, "ready")
+    } else {
+        (StatusCode::SERVICE_UNAVAILABLE, "unavailable")
+    };
+
+    (
+        status_code,
+        Json(ReadyResult {
+            status,
+            data_sources,
+        }),
+    )
+        .into_response()
+}
+
+async fn check(cluster: &Cluster) -> DataSourceStatus {
+    let started = Instant::now();
+    let ping = tokio::time::timeout(
+        PING_TIMEOUT,
+        cluster
+            .client
+            .database("admin")
+            .run_command(doc! {"ping": 1}),
+    )
+    .await;
+    let latency_ms = started.elapsed().as_secs_f64() * 1000.0;
+
+    // Read after the ping so a freshly discovered topology is reflected.
+    let description = cluster.topology.description();
+    let topology_type = description
+        .as_ref()
+        .map_or("Unknown".to_string(), |description| {
+            description.topology_type().to_string()
+        });
+    // Clients built outside `DataSources::connect` have no topology to read.
+    let selectable = match description {
+        Some(description) => {
+            description.has_readable_server(cluster.client.selection_criteria().cloned())
+        }
+        None => matches!(ping, Ok(Ok(_))),
+    };
+
+    let (status, latency_ms, message) = match ping {
+        Ok(Ok(_)) => ("ok", Some(latency_ms), None),
+        Ok(Err(error)) => ("error", None, Some(error.to_string())),
+        Err(_) => (
+            "error",
+            None,
+            Some(format!("Ping timed out after {PING_TIMEOUT:?}")),
+        ),
+    };
+
+    DataSourceStatus {
+        status,
+        topology_type,
+        selectable,
+        latency_ms,
+        message,
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{data_sources::DataSource, ejson::EJSON};
+use mongodb::{
+    bson::{Bson, Document},
+    options::{CommitQuorum, CreateIndexOptions, WriteConcern},
+    IndexModel,
+};
+use serde::Deserialize;
+use std::time::Duration;
+
+use super::results::CreateIndexesResult;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateIndexesBody {
+    db: String,
+    collection: String,
+    indexes: Vec<IndexModel>,
+    options: Option<CreateIndexesOptions>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateIndexesOptions {
+    commit_quorum: Option<CommitQuorum>,
+    #[serde(rename = "maxTimeMS")]
+    max_time_ms: Option<u64>,
+    write_concern: Option<WriteConcern>,
+    comment: Option<Bson>,
+}
+
+impl From<CreateIndexesOptions> for CreateIndexOptions {
+    fn from(options: CreateIndexesOptions) -> Self {
+        CreateIndexOptions::builder()
+            .commit_quorum(options.commit_quorum)
+            .max_time(options.max_time_ms.map(Duration::from_millis))
+            .write_concern(options.write_concern)
+            .comment(options.comment)
+            .build()
+    }
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    EJSON(args): EJSON<CreateIndexesBody>,
+) -> Result<EJSON<CreateIndexesResult>, EJSON<mongodb::error::Error>> {
+    let result = client
+        .database(&args.db)
+        .collection::<Document>(&args.collection)
+        .create_indexes(args.indexes)
+        .with_options(args.options.map(CreateIndexOptions::from))
+        .await
+        .map_err(EJSON)?;
+
+    Ok(EJSON(CreateIndexesResult {
+        index_names: result.index_names,
+    }))
+}
@@ -0,0 +1,26 @@
+use crate::{data_sources::DataSource, ejson::EJSON};
+use mongodb::{bson::Document, options::DropIndexOptions};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct DropIndexBody {
+    db: String,
+    collection: String,
+    name: String,
+    options: Option<DropIndexOptions>,
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    EJSON(args): EJSON<DropIndexBody>,
+) -> Result<EJSON<()>, EJSON<mongodb::error::Error>> {
+    client
+        .database(&args.db)
+        .collection::<Document>(&args.collection)
+        .drop_index(args.name)
+        .with_options(args.options)
+        .await
+        .map_err(EJSON)?;
+
+    Ok(EJSON(()))
+}
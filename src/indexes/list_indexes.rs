@@ -0,0 +1,28 @@
+use crate::{data_sources::DataSource, ejson::EJSON};
+use futures::stream::TryStreamExt;
+use mongodb::{bson::Document, options::ListIndexesOptions, IndexModel};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct ListIndexesBody {
+    db: String,
+    collection: String,
+    options: Option<ListIndexesOptions>,
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    EJSON(args): EJSON<ListIndexesBody>,
+) -> Result<EJSON<Vec<IndexModel>>, EJSON<mongodb::error::Error>> {
+    let cursor = client
+        .database(&args.db)
+        .collection::<Document>(&args.collection)
+        .list_indexes()
+        .with_options(args.options)
+        .await
+        .map_err(EJSON)?;
+
+    let result: Vec<IndexModel> = cursor.try_collect().await.map_err(EJSON)?;
+
+    Ok(EJSON(result))
+}
@@ -0,0 +1,4 @@
+pub mod create_indexes;
+pub mod drop_index;
+pub mod list_indexes;
+pub mod results;
@@ -0,0 +1,7 @@
+use serde::Serialize;
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateIndexesResult {
+    pub index_names: Vec<String>,
+}
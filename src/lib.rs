@@ -1,4 +1,20 @@
+// Handlers and their helpers reject with axum's `Response`, which is above
+// clippy's `result_large_err` size threshold. Rejections are the cold path,
+// and boxing them would only add an allocation before `IntoResponse`.
+#![allow(clippy::result_large_err)]
+
+pub mod admin;
 pub mod app;
+pub mod change_stream;
+pub mod config;
 pub mod crud;
+pub mod cursors;
+pub mod data_api;
+pub mod data_sources;
 pub mod ejson;
+pub mod gridfs;
+pub mod health;
+pub mod indexes;
 pub mod mdb;
+pub mod rest;
+pub mod server;
@@ -1,9 +1,59 @@
-use rs_data_api::app;
+use rs_data_api::{
+    app::{self, AppState, StartupError},
+    config::Config,
+    server,
+};
+use std::process::ExitCode;
 
 #[tokio::main]
-async fn main() {
-    let app_router = app::build().await;
-    let listener = tokio::net::TcpListener::bind("0.0.0.0:8080").await.unwrap();
+async fn main() -> ExitCode {
+    match run().await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run() -> Result<(), StartupError> {
+    let config = Config::load()?;
+    let tls = config
+        .server
+        .tls
+        .as_ref()
+        .map(server::tls::Tls::load)
+        .transpose()?;
+
+    let state = AppState::connect(&config).await?;
+    let listener = server::Listener::bind(&config.server)
+        .await
+        .map_err(|source| StartupError::Bind {
+            listen: config.server.listen.clone(),
+            source,
+        })?;
+
+    app::reload::spawn(state.clone(), config.clone());
+
+    let shutdown = {
+        let state = state.clone();
+
+        async move {
+            server::shutdown_signal().await;
+            state.close_streams();
+        }
+    };
+
+    server::serve(
+        listener,
+        app::router(state.clone()),
+        tls,
+        shutdown,
+        config.server.shutdown_timeout(),
+    )
+    .await;
+
+    state.shutdown().await;
 
-    axum::serve(listener, app_router).await.unwrap();
+    Ok(())
 }
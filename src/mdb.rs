@@ -1,7 +1,182 @@
-use mongodb::Client;
+use mongodb::{
+    bson::doc,
+    error::{Error, ErrorKind},
+    event::{
+        sdam::{SdamEvent, TopologyDescription},
+        EventHandler,
+    },
+    options::ClientOptions,
+    Client,
+};
+use std::{
+    io,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
 
-pub async fn get_client() -> Client {
-    let uri = "mongodb://127.0.0.1:27018";
+use crate::config::MongoConfig;
 
-    Client::with_uri_str(uri).await.unwrap()
+const CONNECT_ATTEMPTS: u32 = 5;
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(8);
+const RECONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+const DEFAULT_APP_NAME: &str = "rs-data-api";
+
+/// Latest topology the driver has discovered for a client.
+#[derive(Clone, Default)]
+pub struct Topology(Arc<RwLock<Option<TopologyDescription>>>);
+
+impl Topology {
+    pub fn description(&self) -> Option<TopologyDescription> {
+        self.0.read().unwrap().clone()
+    }
+
+    fn handler(&self) -> EventHandler<SdamEvent> {
+        let description = self.0.clone();
+
+        EventHandler::callback(move |event| {
+            if let SdamEvent::TopologyDescriptionChanged(event) = event {
+                *description.write().unwrap() = Some(event.new_description);
+            }
+        })
+    }
+}
+
+pub async fn get_client(config: &MongoConfig) -> Result<Client, Error> {
+    Client::with_options(client_options(config).await?)
+}
+
+/// Like `get_client`, but pings the cluster, retrying transient failures.
+pub async fn connect(config: &MongoConfig, topology: &Topology) -> Result<Client, Error> {
+    let client = tracked_client(config, topology).await?;
+    let mut backoff = INITIAL_BACKOFF;
+
+    for attempt in 1.. {
+        match client.database("admin").run_command(doc! {"ping": 1}).await {
+            Ok(_) => break,
+            Err(error) if attempt < CONNECT_ATTEMPTS && is_transient(&error) => {
+                eprintln!(
+                    "Connecting to {} failed (attempt {attempt}/{CONNECT_ATTEMPTS}), retrying in {backoff:?}: {error}",
+                    redact(&config.uri)
+                );
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(MAX_BACKOFF);
+            }
+            Err(error) => return Err(error),
+        }
+    }
+
+    Ok(client)
+}
+
+/// Like `connect`, but with a single bounded attempt so a reload never stalls.
+pub async fn reconnect(config: &MongoConfig, topology: &Topology) -> Result<Client, Error> {
+    let client = tracked_client(config, topology).await?;
+    let admin = client.database("admin");
+    let ping = admin.run_command(doc! {"ping": 1});
+
+    match tokio::time::timeout(RECONNECT_TIMEOUT, ping).await {
+        Ok(result) => result.map(|_| client),
+        Err(_) => Err(io::Error::new(
+            io::ErrorKind::TimedOut,
+            format!("ping timed out after {RECONNECT_TIMEOUT:?}"),
+        )
+        .into()),
+    }
+}
+
+async fn tracked_client(config: &MongoConfig, topology: &Topology) -> Result<Client, Error> {
+    let mut options = client_options(config).await?;
+    options.sdam_event_handler = Some(topology.handler());
+
+    Client::with_options(options)
+}
+
+async fn client_options(config: &MongoConfig) -> Result<ClientOptions, Error> {
+    let mut options = ClientOptions::parse(&config.uri).await?;
+
+    options.app_name = config
+        .app_name
+        .clone()
+        .or(options.app_name)
+        .or_else(|| Some(DEFAULT_APP_NAME.into()));
+    options.min_pool_size = config.min_pool_size.or(options.min_pool_size);
+    options.max_pool_size = config.max_pool_size.or(options.max_pool_size);
+    options.max_idle_time = config
+        .max_idle_time_ms
+        .map(Duration::from_millis)
+        .or(options.max_idle_time);
+    options.connect_timeout = config
+        .connect_timeout_ms
+        .map(Duration::from_millis)
+        .or(options.connect_timeout);
+    options.server_selection_timeout = config
+        .server_selection_timeout_ms
+        .map(Duration::from_millis)
+        .or(options.server_selection_timeout);
+
+    Ok(options)
+}
+
+pub(crate) fn is_transient(error: &Error) -> bool {
+    matches!(
+        *error.kind,
+        ErrorKind::ServerSelection { .. }
+            | ErrorKind::Io(_)
+            | ErrorKind::ConnectionPoolCleared { .. }
+            | ErrorKind::DnsResolve { .. }
+    )
+}
+
+fn redact(uri: &str) -> String {
+    match (uri.find("://"), uri.rfind('@')) {
+        (Some(scheme_end), Some(at)) if at > scheme_end => {
+            format!("{}***{}", &uri[..scheme_end + 3], &uri[at..])
+        }
+        _ => uri.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacts_credentials() {
+        assert_eq!(
+            redact("mongodb://user:secret@db:27017/?tls=true"),
+            "mongodb://***@db:27017/?tls=true"
+        );
+        assert_eq!(redact("mongodb://db:27017"), "mongodb://db:27017");
+    }
+
+    #[tokio::test]
+    async fn app_name_precedence() {
+        let app_name = |uri: &str, app_name: Option<&str>| {
+            let config = MongoConfig {
+                uri: uri.into(),
+                app_name: app_name.map(Into::into),
+                ..MongoConfig::default()
+            };
+
+            async move { client_options(&config).await.unwrap().app_name }
+        };
+
+        assert_eq!(
+            app_name("mongodb://db:27017", None).await.as_deref(),
+            Some("rs-data-api")
+        );
+        assert_eq!(
+            app_name("mongodb://db:27017/?appName=reports", None)
+                .await
+                .as_deref(),
+            Some("reports")
+        );
+        assert_eq!(
+            app_name("mongodb://db:27017/?appName=reports", Some("billing"))
+                .await
+                .as_deref(),
+            Some("billing")
+        );
+    }
 }
@@ -0,0 +1,40 @@
+use crate::{
+    crud::insert_one::{self, InsertOneBody},
+    data_sources::DataSource,
+    ejson::{id, EJSON},
+};
+use axum::{
+    extract::Path,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+};
+use mongodb::bson::Document;
+
+use super::path_segment;
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    Path((db, collection)): Path<(String, String)>,
+    EJSON(document): EJSON<Document>,
+) -> Result<Response, EJSON<mongodb::error::Error>> {
+    let location = format!("/db/{}/{}", path_segment(&db), path_segment(&collection));
+    let body = InsertOneBody {
+        db,
+        collection,
+        document,
+        options: None,
+    };
+
+    let EJSON(result) = insert_one::handler(DataSource(client), EJSON(body)).await?;
+    let location = format!(
+        "{location}/{}",
+        path_segment(&id::format(&result.inserted_id))
+    );
+
+    Ok((
+        StatusCode::CREATED,
+        [(header::LOCATION, location)],
+        EJSON(result),
+    )
+        .into_response())
+}
@@ -0,0 +1,33 @@
+use crate::{
+    crud::delete_one::{self, DeleteOneBody},
+    data_sources::DataSource,
+    ejson::{id, EJSON},
+};
+use axum::{
+    extract::Path,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use mongodb::{bson::doc, results::DeleteResult};
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    Path((db, collection, document_id)): Path<(String, String, String)>,
+) -> Result<(StatusCode, EJSON<DeleteResult>), Response> {
+    let body = DeleteOneBody {
+        db,
+        collection,
+        filter: doc! {"_id": id::parse(&document_id)},
+        options: None,
+    };
+
+    let EJSON(result) = delete_one::handler(DataSource(client), EJSON(body))
+        .await
+        .map_err(IntoResponse::into_response)?;
+
+    if result.deleted_count == 0 {
+        return Err(super::not_found());
+    }
+
+    Ok((StatusCode::OK, EJSON(result)))
+}
@@ -0,0 +1,32 @@
+use crate::{
+    crud::find_one::{self, FindOneBody},
+    data_sources::DataSource,
+    ejson::{id, EJSON},
+};
+use axum::{
+    extract::Path,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use mongodb::bson::{doc, Document};
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    Path((db, collection, document_id)): Path<(String, String, String)>,
+) -> Result<(StatusCode, EJSON<Option<Document>>), Response> {
+    let body = FindOneBody {
+        db,
+        collection,
+        filter: doc! {"_id": id::parse(&document_id)},
+        options: None,
+    };
+
+    let EJSON(document) = find_one::handler(DataSource(client), EJSON(body))
+        .await
+        .map_err(IntoResponse::into_response)?;
+
+    match document {
+        Some(document) => Ok((StatusCode::OK, EJSON(Some(document)))),
+        None => Err(super::not_found()),
+    }
+}
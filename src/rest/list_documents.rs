@@ -0,0 +1,63 @@
+use crate::{
+    crud::find::{self, FindBody},
+    cursors::{CursorOwner, CursorRegistry},
+    data_sources::DataSource,
+    ejson::EJSON,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use mongodb::options::FindOptions;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListDocumentsQuery {
+    filter: Option<String>,
+    projection: Option<String>,
+    sort: Option<String>,
+    limit: Option<i64>,
+    skip: Option<u64>,
+    batch_size: Option<u32>,
+    cursor: Option<bool>,
+}
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    State(cursors): State<CursorRegistry>,
+    owner: CursorOwner,
+    headers: HeaderMap,
+    Path((db, collection)): Path<(String, String)>,
+    Query(query): Query<ListDocumentsQuery>,
+) -> Result<Response, Response> {
+    let filter = super::query_document(query.filter.as_deref(), "filter")?;
+    let options = FindOptions::builder()
+        .projection(super::query_document(
+            query.projection.as_deref(),
+            "projection",
+        )?)
+        .sort(super::query_document(query.sort.as_deref(), "sort")?)
+        .limit(query.limit)
+        .skip(query.skip)
+        .batch_size(query.batch_size)
+        .build();
+    let body = FindBody {
+        db,
+        collection,
+        filter: filter.unwrap_or_default(),
+        options: Some(options),
+        cursor: query.cursor,
+    };
+
+    find::handler(
+        DataSource(client),
+        State(cursors),
+        owner,
+        headers,
+        EJSON(body),
+    )
+    .await
+    .map(|response| (StatusCode::OK, response).into_response())
+}
@@ -0,0 +1,59 @@
+pub mod create_document;
+pub mod delete_document;
+pub mod get_document;
+pub mod list_documents;
+pub mod replace_document;
+pub mod update_document;
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use mongodb::bson::{Bson, Document};
+use serde_json::{json, Value};
+
+pub(crate) fn not_found() -> Response {
+    (
+        StatusCode::NOT_FOUND,
+        Json(json!({"message": "Document not found"})),
+    )
+        .into_response()
+}
+
+/// Percent-encodes `value` for use as a single URL path segment.
+pub(crate) fn path_segment(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                char::from(byte).to_string()
+            }
+            byte => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+pub(crate) fn query_document(
+    value: Option<&str>,
+    name: &str,
+) -> Result<Option<Document>, Response> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+
+    let invalid = || {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(json!({"message": format!("Query parameter {name} must be an EJSON document")})),
+        )
+            .into_response()
+    };
+
+    let value: Value = serde_json::from_str(value).map_err(|_| invalid())?;
+
+    match Bson::try_from(value) {
+        Ok(Bson::Document(document)) => Ok(Some(document)),
+        _ => Err(invalid()),
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{
+    crud::replace_one::{self, ReplaceOneBody},
+    data_sources::DataSource,
+    ejson::{id, EJSON},
+};
+use axum::{extract::Path, http::StatusCode, response::Response};
+use mongodb::{
+    bson::{doc, Document},
+    options::ReplaceOptions,
+    results::UpdateResult,
+};
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    Path((db, collection, document_id)): Path<(String, String, String)>,
+    EJSON(replacement): EJSON<Document>,
+) -> Result<(StatusCode, EJSON<UpdateResult>), Response> {
+    let body = ReplaceOneBody {
+        db,
+        collection,
+        filter: doc! {"_id": id::parse(&document_id)},
+        replacement,
+        options: Some(ReplaceOptions::builder().upsert(true).build()),
+    };
+
+    let EJSON(result) = replace_one::handler(DataSource(client), EJSON(body)).await?;
+    let status = match result.upserted_id {
+        Some(_) => StatusCode::CREATED,
+        None => StatusCode::OK,
+    };
+
+    Ok((status, EJSON(result)))
+}
@@ -0,0 +1,38 @@
+use crate::{
+    crud::update_one::{self, UpdateOneBody},
+    data_sources::DataSource,
+    ejson::{id, EJSON},
+};
+use axum::{
+    extract::Path,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use mongodb::{
+    bson::{doc, Document},
+    results::UpdateResult,
+};
+
+pub async fn handler(
+    DataSource(client): DataSource,
+    Path((db, collection, document_id)): Path<(String, String, String)>,
+    EJSON(update): EJSON<Document>,
+) -> Result<(StatusCode, EJSON<UpdateResult>), Response> {
+    let body = UpdateOneBody {
+        db,
+        collection,
+        query: doc! {"_id": id::parse(&document_id)},
+        update,
+        options: None,
+    };
+
+    let EJSON(result) = update_one::handler(DataSource(client), EJSON(body))
+        .await
+        .map_err(IntoResponse::into_response)?;
+
+    if result.matched_count == 0 {
+        return Err(super::not_found());
+    }
+
+    Ok((StatusCode::OK, EJSON(result)))
+}
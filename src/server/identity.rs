@@ -0,0 +1,55 @@
+use rustls::ServerConnection;
+use x509_parser::parse_x509_certificate;
+
+/// Subject of the verified client certificate when mutual TLS is enabled.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientIdentity {
+    pub subject: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnixPeer {
+    pub uid: u32,
+    pub gid: u32,
+}
+
+impl ClientIdentity {
+    pub fn from_connection(connection: &ServerConnection) -> Option<Self> {
+        let certificate = connection.peer_certificates()?.first()?;
+
+        Self::from_der(certificate.as_ref())
+    }
+
+    pub fn from_der(certificate: &[u8]) -> Option<Self> {
+        let (_, certificate) = parse_x509_certificate(certificate).ok()?;
+
+        Some(Self {
+            subject: certificate.subject().to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustls_pki_types::{pem::PemObject, CertificateDer};
+
+    #[test]
+    fn reads_subject_from_certificate() {
+        let certificate = CertificateDer::from_pem_file(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/tls/client.pem"
+        ))
+        .unwrap();
+
+        assert_eq!(
+            ClientIdentity::from_der(&certificate).unwrap().subject,
+            "O=Example, CN=reporting-service"
+        );
+    }
+
+    #[test]
+    fn rejects_garbage() {
+        assert_eq!(ClientIdentity::from_der(b"not a certificate"), None);
+    }
+}
@@ -0,0 +1,201 @@
+use std::{io, net::SocketAddr};
+use tokio::net::{TcpListener, TcpStream};
+
+use crate::config::{ListenAddress, ServerConfig};
+
+#[cfg(unix)]
+use std::{
+    fs,
+    os::unix::fs::{DirBuilderExt, FileTypeExt, PermissionsExt},
+    path::{Path, PathBuf},
+};
+#[cfg(unix)]
+use tokio::net::{UnixListener, UnixStream};
+
+pub enum Listener {
+    Tcp(TcpListener),
+    #[cfg(unix)]
+    Unix {
+        listener: UnixListener,
+        path: PathBuf,
+    },
+}
+
+pub enum Connection {
+    Tcp(TcpStream, SocketAddr),
+    #[cfg(unix)]
+    Unix(UnixStream),
+}
+
+impl Listener {
+    pub async fn bind(config: &ServerConfig) -> io::Result<Self> {
+        let address = config
+            .listen_address()
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+
+        match address {
+            ListenAddress::Tcp(address) => Ok(Listener::Tcp(TcpListener::bind(address).await?)),
+            #[cfg(unix)]
+            ListenAddress::Unix(path) => {
+                remove_stale_socket(&path)?;
+
+                let listener = match config.socket_mode {
+                    Some(mode) => bind_with_mode(&path, mode)?,
+                    None => UnixListener::bind(&path)?,
+                };
+
+                Ok(Listener::Unix { listener, path })
+            }
+            #[cfg(not(unix))]
+            ListenAddress::Unix(_) => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "unix sockets are not supported on this platform",
+            )),
+        }
+    }
+
+    pub async fn accept(&self) -> io::Result<Connection> {
+        match self {
+            Listener::Tcp(listener) => {
+                let (stream, remote_addr) = listener.accept().await?;
+
+                Ok(Connection::Tcp(stream, remote_addr))
+            }
+            #[cfg(unix)]
+            Listener::Unix { listener, .. } => {
+                let (stream, _) = listener.accept().await?;
+
+                Ok(Connection::Unix(stream))
+            }
+        }
+    }
+
+    pub fn close(self) {
+        #[cfg(unix)]
+        if let Listener::Unix { listener, path } = self {
+            drop(listener);
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+/// Removes a socket file left behind by a process that did not shut down.
+#[cfg(unix)]
+fn remove_stale_socket(path: &Path) -> io::Result<()> {
+    let metadata = match fs::symlink_metadata(path) {
+        Ok(metadata) => metadata,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(error) => return Err(error),
+    };
+
+    if !metadata.file_type().is_socket() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} exists and is not a socket", path.display()),
+        ));
+    }
+
+    match std::os::unix::net::UnixStream::connect(path) {
+        Ok(_) => Err(io::Error::new(
+            io::ErrorKind::AddrInUse,
+            format!("another process is listening on {}", path.display()),
+        )),
+        Err(error) if error.kind() == io::ErrorKind::ConnectionRefused => fs::remove_file(path),
+        Err(error) => Err(error),
+    }
+}
+
+/// Binds in a private directory so the socket is never reachable before its mode is set.
+#[cfg(unix)]
+fn bind_with_mode(path: &Path, mode: u32) -> io::Result<UnixListener> {
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let staging = parent.join(format!(".{name}.{}", std::process::id()));
+    let staged = staging.join("socket");
+
+    fs::DirBuilder::new().mode(0o700).create(&staging)?;
+
+    let result = UnixListener::bind(&staged).and_then(|listener| {
+        fs::set_permissions(&staged, fs::Permissions::from_mode(mode))?;
+        fs::rename(&staged, path)?;
+
+        Ok(listener)
+    });
+
+    let _ = fs::remove_file(&staged);
+    let _ = fs::remove_dir(&staging);
+
+    result
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    fn socket_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("rs-data-api-{}-{name}.sock", std::process::id()))
+    }
+
+    fn unix_config(path: &Path, socket_mode: Option<u32>) -> ServerConfig {
+        ServerConfig {
+            listen: format!("unix:{}", path.display()),
+            socket_mode,
+            ..ServerConfig::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn binds_unix_socket_with_mode() {
+        let path = socket_path("mode");
+        let listener = Listener::bind(&unix_config(&path, Some(0o600)))
+            .await
+            .unwrap();
+        let metadata = fs::metadata(&path).unwrap();
+
+        assert!(metadata.file_type().is_socket());
+        assert_eq!(metadata.permissions().mode() & 0o777, 0o600);
+        assert!(std::os::unix::net::UnixStream::connect(&path).is_ok());
+        assert!(fs::read_dir(std::env::temp_dir())
+            .unwrap()
+            .filter_map(Result::ok)
+            .all(|entry| !entry.path().join("socket").exists()));
+
+        listener.close();
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn replaces_stale_socket() {
+        let path = socket_path("stale");
+        drop(std::os::unix::net::UnixListener::bind(&path).unwrap());
+
+        let listener = Listener::bind(&unix_config(&path, None)).await.unwrap();
+
+        listener.close();
+    }
+
+    #[tokio::test]
+    async fn refuses_live_socket_and_other_files() {
+        let path = socket_path("live");
+        let live = Listener::bind(&unix_config(&path, None)).await.unwrap();
+        let error = Listener::bind(&unix_config(&path, None))
+            .await
+            .err()
+            .unwrap();
+
+        assert_eq!(error.kind(), io::ErrorKind::AddrInUse);
+        live.close();
+
+        fs::write(&path, "not a socket").unwrap();
+        let error = Listener::bind(&unix_config(&path, None))
+            .await
+            .err()
+            .unwrap();
+
+        assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,420 @@
+pub mod identity;
+pub mod listener;
+pub mod tls;
+
+pub use identity::{ClientIdentity, UnixPeer};
+pub use listener::Listener;
+
+use axum::{body::Body, extract::ConnectInfo, Router};
+use hyper::{body::Incoming, service::service_fn, Request};
+use hyper_util::{
+    rt::{TokioExecutor, TokioIo},
+    server::{
+        conn::auto::Builder,
+        graceful::{GracefulShutdown, Watcher},
+    },
+};
+use listener::Connection;
+use std::{future::Future, io, net::SocketAddr, time::Duration};
+use tls::Tls;
+use tokio::{
+    io::{AsyncRead, AsyncWrite},
+    signal,
+    task::JoinSet,
+};
+use tower::ServiceExt;
+
+pub async fn serve(
+    listener: Listener,
+    router: Router,
+    tls: Option<Tls>,
+    shutdown: impl Future<Output = ()>,
+    drain_timeout: Duration,
+) {
+    let graceful = GracefulShutdown::new();
+    let mut connections = JoinSet::new();
+
+    tokio::pin!(shutdown);
+
+    loop {
+        let connection = tokio::select! {
+            result = listener.accept() => match result {
+                Ok(connection) => connection,
+                Err(error) => {
+                    handle_accept_error(error).await;
+                    continue;
+                }
+            },
+            () = &mut shutdown => break,
+        };
+
+        while connections.try_join_next().is_some() {}
+
+        let router = router.clone();
+        let tls = tls.clone();
+        let watcher = graceful.watcher();
+
+        connections.spawn(async move {
+            match connection {
+                Connection::Tcp(stream, remote_addr) => {
+                    handle(stream, Peer::Tcp(remote_addr), router, tls, watcher).await
+                }
+                #[cfg(unix)]
+                Connection::Unix(stream) => {
+                    let Ok(credentials) = stream.peer_cred() else {
+                        return;
+                    };
+                    let peer = Peer::Unix(UnixPeer {
+                        uid: credentials.uid(),
+                        gid: credentials.gid(),
+                    });
+
+                    handle(stream, peer, router, tls, watcher).await
+                }
+            }
+        });
+    }
+
+    listener.close();
+
+    if tokio::time::timeout(drain_timeout, graceful.shutdown())
+        .await
+        .is_err()
+    {
+        eprintln!(
+            "Closing {} connection(s) still open after {drain_timeout:?}",
+            connections.len()
+        );
+    }
+
+    connections.shutdown().await;
+}
+
+pub async fn shutdown_signal() {
+    let interrupt = async {
+        let _ = signal::ctrl_c().await;
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match signal::unix::signal(signal::unix::SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                terminate.recv().await;
+            }
+            Err(_) => std::future::pending().await,
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        () = interrupt => {},
+        () = terminate => {},
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Peer {
+    Tcp(SocketAddr),
+    #[cfg(unix)]
+    Unix(UnixPeer),
+}
+
+async fn handle<I>(io: I, peer: Peer, router: Router, tls: Option<Tls>, watcher: Watcher)
+where
+    I: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    match tls {
+        Some(tls) => {
+            let handshake = tls.acceptor.accept(io);
+            let Ok(Ok(stream)) = tokio::time::timeout(tls.handshake_timeout, handshake).await
+            else {
+                return;
+            };
+            let identity = ClientIdentity::from_connection(stream.get_ref().1);
+
+            serve_connection(stream, peer, identity, router, watcher).await;
+        }
+        None => serve_connection(io, peer, None, router, watcher).await,
+    }
+}
+
+async fn serve_connection<I>(
+    io: I,
+    peer: Peer,
+    identity: Option<ClientIdentity>,
+    router: Router,
+    watcher: Watcher,
+) where
+    I: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    let service = service_fn(move |mut request: Request<Incoming>| {
+        match peer {
+            Peer::Tcp(remote_addr) => {
+                request.extensions_mut().insert(ConnectInfo(remote_addr));
+            }
+            #[cfg(unix)]
+            Peer::Unix(unix_peer) => {
+                request.extensions_mut().insert(unix_peer);
+            }
+        }
+
+        if let Some(identity) = &identity {
+            request.extensions_mut().insert(identity.clone());
+        }
+
+        router.clone().oneshot(request.map(Body::new))
+    });
+    let builder = Builder::new(TokioExecutor::new());
+    let connection = builder.serve_connection_with_upgrades(TokioIo::new(io), service);
+
+    let _ = watcher.watch(connection).await;
+}
+
+async fn handle_accept_error(error: io::Error) {
+    if matches!(
+        error.kind(),
+        io::ErrorKind::ConnectionRefused
+            | io::ErrorKind::ConnectionAborted
+            | io::ErrorKind::ConnectionReset
+    ) {
+        return;
+    }
+
+    eprintln!("Failed to accept connection: {error}");
+    tokio::time::sleep(Duration::from_secs(1)).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::routing::get;
+    use std::sync::Arc;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpStream,
+        sync::oneshot,
+        task::JoinHandle,
+        time::Instant,
+    };
+
+    async fn start(drain_timeout: Duration) -> (SocketAddr, oneshot::Sender<()>, JoinHandle<()>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let router = Router::new().route(
+            "/slow",
+            get(|| async {
+                tokio::time::sleep(Duration::from_millis(300)).await;
+                "done"
+            }),
+        );
+        let (trigger, shutdown) = oneshot::channel::<()>();
+        let server = tokio::spawn(serve(
+            Listener::Tcp(listener),
+            router,
+            None,
+            async {
+                let _ = shutdown.await;
+            },
+            drain_timeout,
+        ));
+
+        (addr, trigger, server)
+    }
+
+    async fn request_slow(addr: SocketAddr) -> String {
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(b"GET /slow HTTP/1.1\r\nhost: localhost\r\n\r\n")
+            .await
+            .unwrap();
+
+        let mut response = String::new();
+        let _ = stream.read_to_string(&mut response).await;
+
+        response
+    }
+
+    #[tokio::test]
+    async fn drains_in_flight_requests() {
+        let (addr, trigger, server) = start(Duration::from_secs(5)).await;
+        let request = tokio::spawn(request_slow(addr));
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        trigger.send(()).unwrap();
+        server.await.unwrap();
+
+        let response = request.await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.ends_with("done"));
+        assert!(TcpStream::connect(addr).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn drops_requests_past_deadline() {
+        let (addr, trigger, server) = start(Duration::from_millis(50)).await;
+        let request = tokio::spawn(request_slow(addr));
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let started = Instant::now();
+        trigger.send(()).unwrap();
+        server.await.unwrap();
+
+        assert!(started.elapsed() < Duration::from_millis(250));
+        assert_eq!(request.await.unwrap(), "");
+    }
+
+    #[tokio::test]
+    async fn closes_stalled_tls_handshakes() {
+        let fixture = |name: &str| {
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests/fixtures/tls")
+                .join(name)
+        };
+        let mut tls = Tls::load(&crate::config::TlsConfig {
+            cert_path: fixture("server.pem"),
+            key_path: fixture("server.key"),
+            client_ca_path: None,
+            reload_interval_secs: 30,
+            handshake_timeout_secs: 10,
+        })
+        .unwrap();
+        tls.handshake_timeout = Duration::from_millis(100);
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (trigger, shutdown) = oneshot::channel::<()>();
+        let server = tokio::spawn(serve(
+            Listener::Tcp(listener),
+            Router::new(),
+            Some(tls),
+            async {
+                let _ = shutdown.await;
+            },
+            Duration::from_secs(5),
+        ));
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        let started = Instant::now();
+        let read = stream.read(&mut [0; 1]).await;
+
+        assert!(matches!(read, Ok(0) | Err(_)));
+        assert!(started.elapsed() < Duration::from_secs(2));
+
+        trigger.send(()).unwrap();
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn passes_client_identity_to_handlers() {
+        use axum::Extension;
+        use rustls::{crypto::ring, ClientConfig, RootCertStore};
+        use rustls_pki_types::{pem::PemObject, CertificateDer, PrivateKeyDer, ServerName};
+        use tokio_rustls::TlsConnector;
+
+        let fixture = |name: &str| {
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests/fixtures/tls")
+                .join(name)
+        };
+        let tls = Tls::load(&crate::config::TlsConfig {
+            cert_path: fixture("server.pem"),
+            key_path: fixture("server.key"),
+            client_ca_path: Some(fixture("ca.pem")),
+            reload_interval_secs: 30,
+            handshake_timeout_secs: 10,
+        })
+        .unwrap();
+        let router = Router::new().route(
+            "/",
+            get(|Extension(identity): Extension<ClientIdentity>| async move { identity.subject }),
+        );
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (trigger, shutdown) = oneshot::channel::<()>();
+        let server = tokio::spawn(serve(
+            Listener::Tcp(listener),
+            router,
+            Some(tls),
+            async {
+                let _ = shutdown.await;
+            },
+            Duration::from_secs(1),
+        ));
+
+        let mut roots = RootCertStore::empty();
+        roots
+            .add(CertificateDer::from_pem_file(fixture("ca.pem")).unwrap())
+            .unwrap();
+        let client_config = ClientConfig::builder_with_provider(Arc::new(ring::default_provider()))
+            .with_safe_default_protocol_versions()
+            .unwrap()
+            .with_root_certificates(roots)
+            .with_client_auth_cert(
+                vec![CertificateDer::from_pem_file(fixture("client.pem")).unwrap()],
+                PrivateKeyDer::from_pem_file(fixture("client.key")).unwrap(),
+            )
+            .unwrap();
+        let stream = TcpStream::connect(addr).await.unwrap();
+        let mut stream = TlsConnector::from(Arc::new(client_config))
+            .connect(ServerName::try_from("localhost").unwrap(), stream)
+            .await
+            .unwrap();
+
+        stream
+            .write_all(b"GET / HTTP/1.1\r\nhost: localhost\r\nconnection: close\r\n\r\n")
+            .await
+            .unwrap();
+
+        let mut response = String::new();
+        let _ = stream.read_to_string(&mut response).await;
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.ends_with("O=Example, CN=reporting-service"));
+
+        trigger.send(()).unwrap();
+        server.await.unwrap();
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn serves_unix_socket() {
+        let path =
+            std::env::temp_dir().join(format!("rs-data-api-{}-serve.sock", std::process::id()));
+        let config = crate::config::ServerConfig {
+            listen: format!("unix:{}", path.display()),
+            ..Default::default()
+        };
+        let listener = Listener::bind(&config).await.unwrap();
+        let router = Router::new().route("/", get(|| async { "hello" }));
+        let (trigger, shutdown) = oneshot::channel::<()>();
+        let server = tokio::spawn(serve(
+            listener,
+            router,
+            None,
+            async {
+                let _ = shutdown.await;
+            },
+            Duration::from_secs(1),
+        ));
+
+        let mut stream = tokio::net::UnixStream::connect(&path).await.unwrap();
+        stream
+            .write_all(b"GET / HTTP/1.1\r\nhost: localhost\r\nconnection: close\r\n\r\n")
+            .await
+            .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.ends_with("hello"));
+
+        trigger.send(()).unwrap();
+        server.await.unwrap();
+
+        assert!(!path.exists());
+    }
+}
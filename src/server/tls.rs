@@ -0,0 +1,274 @@
+use rustls::{
+    crypto::ring,
+    server::{ClientHello, ResolvesServerCert, WebPkiClientVerifier},
+    sign::CertifiedKey,
+    RootCertStore, ServerConfig,
+};
+use rustls_pki_types::{pem::PemObject, CertificateDer, PrivateKeyDer};
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock, Weak},
+    time::{Duration, SystemTime},
+};
+use tokio_rustls::TlsAcceptor;
+
+use crate::config::TlsConfig;
+
+#[derive(Debug)]
+pub enum TlsError {
+    Pem {
+        path: PathBuf,
+        source: rustls_pki_types::pem::Error,
+    },
+    NoCertificates(PathBuf),
+    Rustls(rustls::Error),
+    ClientVerifier(rustls::server::VerifierBuilderError),
+}
+
+#[derive(Clone)]
+pub struct Tls {
+    pub acceptor: TlsAcceptor,
+    pub handshake_timeout: Duration,
+}
+
+impl Tls {
+    pub fn load(config: &TlsConfig) -> Result<Self, TlsError> {
+        Ok(Self {
+            acceptor: acceptor(config)?,
+            handshake_timeout: config.handshake_timeout(),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct CertificateResolver {
+    cert_path: PathBuf,
+    key_path: PathBuf,
+    certified_key: RwLock<Arc<CertifiedKey>>,
+}
+
+impl CertificateResolver {
+    pub fn load(cert_path: &Path, key_path: &Path) -> Result<Self, TlsError> {
+        Ok(Self {
+            cert_path: cert_path.to_path_buf(),
+            key_path: key_path.to_path_buf(),
+            certified_key: RwLock::new(Arc::new(load_certified_key(cert_path, key_path)?)),
+        })
+    }
+
+    pub fn reload(&self) -> Result<(), TlsError> {
+        let certified_key = load_certified_key(&self.cert_path, &self.key_path)?;
+
+        *self.certified_key.write().unwrap() = Arc::new(certified_key);
+
+        Ok(())
+    }
+
+    pub fn spawn_reloader(self: &Arc<Self>, config: &TlsConfig) {
+        let resolver: Weak<_> = Arc::downgrade(self);
+        let period = config.reload_interval();
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(period);
+            let mut last_modified = None;
+
+            loop {
+                interval.tick().await;
+
+                let Some(resolver) = resolver.upgrade() else {
+                    break;
+                };
+                let modified = resolver.modified();
+
+                if last_modified.is_none() {
+                    last_modified = modified;
+                    continue;
+                }
+
+                if modified == last_modified {
+                    continue;
+                }
+
+                last_modified = modified;
+
+                if let Err(error) = resolver.reload() {
+                    eprintln!("Failed to reload TLS certificate: {error}");
+                }
+            }
+        });
+    }
+
+    fn modified(&self) -> Option<SystemTime> {
+        [&self.cert_path, &self.key_path]
+            .into_iter()
+            .filter_map(|path| fs::metadata(path).and_then(|meta| meta.modified()).ok())
+            .max()
+    }
+}
+
+impl ResolvesServerCert for CertificateResolver {
+    fn resolve(&self, _client_hello: ClientHello) -> Option<Arc<CertifiedKey>> {
+        Some(self.certified_key.read().unwrap().clone())
+    }
+}
+
+fn acceptor(config: &TlsConfig) -> Result<TlsAcceptor, TlsError> {
+    let provider = Arc::new(ring::default_provider());
+    let resolver = Arc::new(CertificateResolver::load(
+        &config.cert_path,
+        &config.key_path,
+    )?);
+
+    resolver.spawn_reloader(config);
+
+    let builder = ServerConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()
+        .map_err(TlsError::Rustls)?;
+
+    let builder = match &config.client_ca_path {
+        Some(path) => builder.with_client_cert_verifier(
+            WebPkiClientVerifier::builder_with_provider(Arc::new(load_roots(path)?), provider)
+                .build()
+                .map_err(TlsError::ClientVerifier)?,
+        ),
+        None => builder.with_no_client_auth(),
+    };
+
+    let mut server_config = builder.with_cert_resolver(resolver);
+    server_config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+
+    Ok(TlsAcceptor::from(Arc::new(server_config)))
+}
+
+fn load_certified_key(cert_path: &Path, key_path: &Path) -> Result<CertifiedKey, TlsError> {
+    let certificates = load_certificates(cert_path)?;
+    let key = PrivateKeyDer::from_pem_file(key_path).map_err(|source| TlsError::Pem {
+        path: key_path.to_path_buf(),
+        source,
+    })?;
+    let signing_key = ring::sign::any_supported_type(&key).map_err(TlsError::Rustls)?;
+    let certified_key = CertifiedKey::new(certificates, signing_key);
+
+    // A rotation that replaces one file before the other must not install a
+    // pair whose handshakes would fail.
+    certified_key.keys_match().map_err(TlsError::Rustls)?;
+
+    Ok(certified_key)
+}
+
+fn load_roots(path: &Path) -> Result<RootCertStore, TlsError> {
+    let mut roots = RootCertStore::empty();
+
+    for certificate in load_certificates(path)? {
+        roots.add(certificate).map_err(TlsError::Rustls)?;
+    }
+
+    Ok(roots)
+}
+
+fn load_certificates(path: &Path) -> Result<Vec<CertificateDer<'static>>, TlsError> {
+    let pem_error = |source| TlsError::Pem {
+        path: path.to_path_buf(),
+        source,
+    };
+    let certificates = CertificateDer::pem_file_iter(path)
+        .map_err(pem_error)?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(pem_error)?;
+
+    if certificates.is_empty() {
+        return Err(TlsError::NoCertificates(path.to_path_buf()));
+    }
+
+    Ok(certificates)
+}
+
+impl fmt::Display for TlsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TlsError::Pem { path, source } => {
+                write!(f, "Failed to read {}: {source}", path.display())
+            }
+            TlsError::NoCertificates(path) => {
+                write!(f, "No certificates found in {}", path.display())
+            }
+            TlsError::Rustls(error) => write!(f, "Invalid TLS setup: {error}"),
+            TlsError::ClientVerifier(error) => {
+                write!(f, "Invalid client CA certificates: {error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TlsError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/tls")
+            .join(name)
+    }
+
+    fn tls_config(client_ca: Option<&str>) -> TlsConfig {
+        TlsConfig {
+            cert_path: fixture("server.pem"),
+            key_path: fixture("server.key"),
+            client_ca_path: client_ca.map(fixture),
+            reload_interval_secs: 30,
+            handshake_timeout_secs: 10,
+        }
+    }
+
+    #[test]
+    fn rejects_mismatched_key() {
+        assert!(matches!(
+            CertificateResolver::load(&fixture("server.pem"), &fixture("client.key")),
+            Err(TlsError::Rustls(rustls::Error::InconsistentKeys(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn builds_acceptor() {
+        assert!(Tls::load(&tls_config(None)).is_ok());
+        assert!(Tls::load(&tls_config(Some("ca.pem"))).is_ok());
+    }
+
+    #[test]
+    fn reload_swaps_certificate() {
+        let dir = std::env::temp_dir().join(format!("rs-data-api-tls-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let (cert_path, key_path) = (dir.join("cert.pem"), dir.join("key.pem"));
+        fs::copy(fixture("server.pem"), &cert_path).unwrap();
+        fs::copy(fixture("server.key"), &key_path).unwrap();
+
+        let resolver = CertificateResolver::load(&cert_path, &key_path).unwrap();
+        let before = resolver.certified_key.read().unwrap().cert[0].clone();
+
+        fs::copy(fixture("client.pem"), &cert_path).unwrap();
+        fs::copy(fixture("client.key"), &key_path).unwrap();
+        resolver.reload().unwrap();
+
+        let after = resolver.certified_key.read().unwrap().cert[0].clone();
+        assert_ne!(before, after);
+
+        fs::copy(fixture("server.pem"), &cert_path).unwrap();
+        assert!(matches!(
+            resolver.reload(),
+            Err(TlsError::Rustls(rustls::Error::InconsistentKeys(_)))
+        ));
+        assert_eq!(resolver.certified_key.read().unwrap().cert[0], after);
+
+        fs::write(&cert_path, "garbage").unwrap();
+        assert!(matches!(
+            resolver.reload(),
+            Err(TlsError::NoCertificates(_))
+        ));
+        assert_eq!(resolver.certified_key.read().unwrap().cert[0], after);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
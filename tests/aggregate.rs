@@ -0,0 +1,118 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::{
+        bson::{doc, oid::ObjectId, Document},
+        error::CommandError,
+    };
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{
+        drop_database, get_db_and_collection, get_struct_from_doc, one_shot_array,
+        one_shot_document,
+    };
+
+    #[derive(Serialize, Deserialize)]
+    struct AggregateBody {
+        pub db: String,
+        pub collection: String,
+        pub pipeline: Vec<Document>,
+        pub options: Option<Document>,
+    }
+
+    #[tokio::test]
+    async fn aggregate() {
+        let (db, collection) = get_db_and_collection().await;
+        let user_0 = doc! { "_id": ObjectId::new(), "name": "john", "age": 30 };
+        let user_1 = doc! { "_id": ObjectId::new(), "name": "jim", "age": 30 };
+        let user_2 = doc! { "_id": ObjectId::new(), "name": "jones", "age": 31 };
+
+        collection
+            .insert_many([&user_0, &user_1, &user_2])
+            .await
+            .unwrap();
+
+        let body = AggregateBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            pipeline: vec![
+                doc! {"$group": {"_id": "$age", "count": {"$sum": 1}}},
+                doc! {"$sort": {"_id": 1}},
+            ],
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_array("/aggregate", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc.len(), 2);
+        assert_eq!(
+            doc.first().unwrap().as_document().unwrap(),
+            &doc! {"_id": 30, "count": 2}
+        );
+        assert_eq!(
+            doc.get(1).unwrap().as_document().unwrap(),
+            &doc! {"_id": 31, "count": 1}
+        );
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn aggregate_with_options() {
+        let (db, collection) = get_db_and_collection().await;
+        let user_0 = doc! { "_id": ObjectId::new(), "name": "john", "age": 30 };
+        let user_1 = doc! { "_id": ObjectId::new(), "name": "jim", "age": 31 };
+
+        collection.insert_many([&user_0, &user_1]).await.unwrap();
+
+        let body = AggregateBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            pipeline: vec![
+                doc! {"$sort": {"age": -1}},
+                doc! {"$project": {"_id": 0, "name": 1}},
+            ],
+            options: Some(doc! {
+                "allowDiskUse": true,
+                "batchSize": 1,
+                "comment": "My aggregate operation",
+            }),
+        };
+
+        let (parts, doc) = one_shot_array("/aggregate", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc.len(), 2);
+        assert_eq!(
+            doc.first().unwrap().as_document().unwrap(),
+            &doc! {"name": "jim"}
+        );
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn aggregate_error() {
+        let (db, collection) = get_db_and_collection().await;
+
+        let body = AggregateBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            pipeline: vec![doc! {"$unknownStage": {}}],
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/aggregate", body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+
+        let error = get_struct_from_doc::<CommandError>(doc);
+
+        assert!(error.message.contains("Unrecognized pipeline stage name"));
+
+        drop_database(&db).await;
+    }
+}
@@ -0,0 +1,129 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::bson::{doc, oid::ObjectId, Document};
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{drop_database, get_db_and_collection, one_shot_document};
+
+    #[derive(Serialize, Deserialize)]
+    struct BulkWriteBody {
+        pub db: String,
+        pub collection: String,
+        pub operations: Vec<Document>,
+        pub options: Option<Document>,
+    }
+
+    #[tokio::test]
+    async fn bulk_write() {
+        let (db, collection) = get_db_and_collection().await;
+        let user_0 = doc! { "_id": ObjectId::new(), "name": "john", "age": 30 };
+        let user_1 = doc! { "_id": ObjectId::new(), "name": "jim", "age": 31 };
+        let user_2 = doc! { "_id": ObjectId::new(), "name": "jones", "age": 32 };
+
+        collection.insert_many([&user_0, &user_1]).await.unwrap();
+
+        let body = BulkWriteBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            operations: vec![
+                doc! {"insertOne": {"document": &user_2}},
+                doc! {"updateOne": {"filter": {"name": "john"}, "update": {"$inc": {"age": 1}}}},
+                doc! {"updateMany": {"filter": {}, "update": {"$set": {"active": true}}}},
+                doc! {"replaceOne": {"filter": {"name": "jim"}, "replacement": {"name": "jim"}}},
+                doc! {"deleteOne": {"filter": {"name": "jones"}}},
+                doc! {"deleteMany": {"filter": {"age": {"$gt": 100}}}},
+            ],
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/bulkWrite", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc.get_i64("insertedCount").unwrap(), 1);
+        assert_eq!(doc.get_i64("matchedCount").unwrap(), 5);
+        assert_eq!(doc.get_i64("modifiedCount").unwrap(), 5);
+        assert_eq!(doc.get_i64("deletedCount").unwrap(), 1);
+        assert_eq!(
+            doc.get_document("insertResults")
+                .unwrap()
+                .get_document("0")
+                .unwrap()
+                .get("insertedId"),
+            user_2.get("_id")
+        );
+        assert!(doc.get_document("updateResults").unwrap().contains_key("3"));
+        assert!(doc.get_document("deleteResults").unwrap().contains_key("5"));
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn bulk_write_unordered() {
+        let (db, collection) = get_db_and_collection().await;
+        let user_0 = doc! { "_id": ObjectId::new(), "name": "john", "age": 30 };
+        let user_1 = doc! { "_id": ObjectId::new(), "name": "jim", "age": 31 };
+
+        collection.insert_one(&user_0).await.unwrap();
+
+        let body = BulkWriteBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            operations: vec![
+                doc! {"insertOne": {"document": &user_0}},
+                doc! {"insertOne": {"document": &user_1}},
+            ],
+            options: Some(doc! {"ordered": false}),
+        };
+
+        let (parts, doc) = one_shot_document("/bulkWrite", body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+
+        let write_error = doc
+            .get_document("writeErrors")
+            .unwrap()
+            .get_document("0")
+            .unwrap();
+
+        assert!(write_error
+            .get_str("errmsg")
+            .unwrap()
+            .contains("E11000 duplicate key error"));
+        assert_eq!(
+            doc.get_document("partialResult")
+                .unwrap()
+                .get_i64("insertedCount")
+                .unwrap(),
+            1
+        );
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn bulk_write_error() {
+        let (db, collection) = get_db_and_collection().await;
+
+        let body = BulkWriteBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            operations: vec![doc! {
+                "replaceOne": {"filter": {"name": "john"}, "replacement": {"$set": {"age": 1}}}
+            }],
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/bulkWrite", body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+        assert!(doc
+            .get_str("message")
+            .unwrap()
+            .contains("must not contain update operators"));
+
+        drop_database(&db).await;
+    }
+}
@@ -0,0 +1,106 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::{
+        bson::{doc, oid::ObjectId, Document},
+        error::CommandError,
+    };
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{
+        drop_database, get_db_and_collection, get_struct_from_doc, one_shot_document,
+    };
+
+    #[derive(Serialize, Deserialize)]
+    struct CountDocumentsBody {
+        pub db: String,
+        pub collection: String,
+        pub filter: Document,
+        pub options: Option<Document>,
+    }
+
+    #[tokio::test]
+    async fn count_documents() {
+        let (db, collection) = get_db_and_collection().await;
+        let user_0 = doc! { "_id": ObjectId::new(), "name": "john", "age": 30 };
+        let user_1 = doc! { "_id": ObjectId::new(), "name": "jim", "age": 30 };
+        let user_2 = doc! { "_id": ObjectId::new(), "name": "jones", "age": 31 };
+
+        collection
+            .insert_many([&user_0, &user_1, &user_2])
+            .await
+            .unwrap();
+
+        let body = CountDocumentsBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            filter: doc! {"age": 30},
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/countDocuments", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc.get_i64("count").unwrap(), 2);
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn count_documents_with_options() {
+        let (db, collection) = get_db_and_collection().await;
+        let user_0 = doc! { "_id": ObjectId::new(), "name": "john", "age": 30 };
+        let user_1 = doc! { "_id": ObjectId::new(), "name": "jim", "age": 30 };
+        let user_2 = doc! { "_id": ObjectId::new(), "name": "jones", "age": 30 };
+
+        collection
+            .insert_many([&user_0, &user_1, &user_2])
+            .await
+            .unwrap();
+
+        let body = CountDocumentsBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            filter: doc! {"age": 30},
+            options: Some(doc! {
+                "skip": 1,
+                "limit": 1,
+                "comment": "My count documents operation",
+            }),
+        };
+
+        let (parts, doc) = one_shot_document("/countDocuments", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc.get_i64("count").unwrap(), 1);
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn count_documents_error() {
+        let (db, collection) = get_db_and_collection().await;
+        let user = doc! { "_id": ObjectId::new(), "name": "john", "age": 30 };
+
+        collection.insert_one(&user).await.unwrap();
+
+        let body = CountDocumentsBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            filter: doc! {"age": doc!{"$in": {}}},
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/countDocuments", body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+
+        let error = get_struct_from_doc::<CommandError>(doc);
+
+        assert!(error.message.contains("$in needs an array"));
+
+        drop_database(&db).await;
+    }
+}
@@ -0,0 +1,93 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::{
+        bson::{doc, Document},
+        error::CommandError,
+    };
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{
+        drop_database, get_db_and_collection, get_struct_from_doc, one_shot_document,
+    };
+
+    #[derive(Serialize, Deserialize)]
+    struct CreateCollectionBody {
+        pub db: String,
+        pub collection: String,
+        pub options: Option<Document>,
+    }
+
+    #[tokio::test]
+    async fn create_collection() {
+        let (db, _) = get_db_and_collection().await;
+
+        let body = CreateCollectionBody {
+            db: db.name().into(),
+            collection: "users".into(),
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/createCollection", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc, doc! {});
+        assert_eq!(db.list_collection_names().await.unwrap(), vec!["users"]);
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn create_collection_with_options() {
+        let (db, _) = get_db_and_collection().await;
+
+        let body = CreateCollectionBody {
+            db: db.name().into(),
+            collection: "events".into(),
+            options: Some(doc! {
+                "capped": true,
+                "size": 4096,
+                "validator": {"$jsonSchema": {"required": ["name"]}},
+                "collation": {"locale": "en"},
+            }),
+        };
+
+        let (parts, _) = one_shot_document("/createCollection", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+
+        let insert = db
+            .collection::<Document>("events")
+            .insert_one(doc! {"age": 30})
+            .await;
+
+        assert!(insert.is_err());
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn create_collection_error() {
+        let (db, _) = get_db_and_collection().await;
+
+        db.create_collection("users").await.unwrap();
+
+        let body = CreateCollectionBody {
+            db: db.name().into(),
+            collection: "users".into(),
+            options: Some(doc! {"capped": true, "size": 4096}),
+        };
+
+        let (parts, doc) = one_shot_document("/createCollection", body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+
+        let error = get_struct_from_doc::<CommandError>(doc);
+
+        assert_eq!(error.code_name, "NamespaceExists");
+
+        drop_database(&db).await;
+    }
+}
@@ -0,0 +1,108 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::{
+        bson::{doc, oid::ObjectId, Bson, Document},
+        error::CommandError,
+    };
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{
+        drop_database, get_db_and_collection, get_struct_from_doc, one_shot_document,
+    };
+
+    #[derive(Serialize, Deserialize)]
+    struct CreateIndexesBody {
+        pub db: String,
+        pub collection: String,
+        pub indexes: Vec<Document>,
+        pub options: Option<Document>,
+    }
+
+    #[tokio::test]
+    async fn create_indexes() {
+        let (db, collection) = get_db_and_collection().await;
+
+        let body = CreateIndexesBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            indexes: vec![
+                doc! {"key": {"name": 1}, "unique": true},
+                doc! {"key": {"createdAt": 1}, "name": "ttl", "expireAfterSeconds": 3600},
+            ],
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/createIndexes", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(
+            doc.get_array("indexNames").unwrap(),
+            &vec![Bson::from("name_1"), Bson::from("ttl")]
+        );
+
+        let user = doc! { "_id": ObjectId::new(), "name": "john", "age": 30 };
+
+        collection.insert_one(&user).await.unwrap();
+
+        let duplicate = doc! { "_id": ObjectId::new(), "name": "john", "age": 31 };
+
+        assert!(collection.insert_one(&duplicate).await.is_err());
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn create_indexes_with_options() {
+        let (db, collection) = get_db_and_collection().await;
+
+        let body = CreateIndexesBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            indexes: vec![doc! {
+                "key": {"age": 1},
+                "name": "adults",
+                "partialFilterExpression": {"age": {"$gte": 18}},
+                "collation": {"locale": "en", "strength": 2},
+            }],
+            options: Some(doc! {
+                "maxTimeMS": 1000,
+                "comment": "My create indexes operation",
+            }),
+        };
+
+        let (parts, doc) = one_shot_document("/createIndexes", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(
+            doc.get_array("indexNames").unwrap(),
+            &vec![Bson::from("adults")]
+        );
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn create_indexes_error() {
+        let (db, collection) = get_db_and_collection().await;
+
+        let body = CreateIndexesBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            indexes: vec![doc! {"key": {"name": "unknown"}}],
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/createIndexes", body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+
+        let error = get_struct_from_doc::<CommandError>(doc);
+
+        assert!(error.message.contains("Unknown index plugin"));
+
+        drop_database(&db).await;
+    }
+}
@@ -0,0 +1,267 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        http::{header, response::Parts, Method, Request, StatusCode},
+    };
+    use mongodb::bson::{doc, oid::ObjectId, Document};
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    use crate::helpers::{
+        build_app, drop_database, get_body_ejson_from_struct, get_db_and_collection,
+        get_document_from_body,
+    };
+
+    async fn action(name: &str, content_type: &str, body: Body) -> (Parts, Document) {
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(format!("/app/data-abcde/endpoint/data/v1/action/{name}"))
+            .header(header::CONTENT_TYPE, content_type)
+            .header(header::ACCEPT, content_type)
+            .body(body)
+            .unwrap();
+        let (parts, body) = build_app()
+            .await
+            .oneshot(request)
+            .await
+            .unwrap()
+            .into_parts();
+        let doc = get_document_from_body(body).await;
+
+        (parts, doc)
+    }
+
+    fn json_body(value: Value) -> Body {
+        Body::from(value.to_string())
+    }
+
+    #[tokio::test]
+    async fn data_api() {
+        let (db, collection) = get_db_and_collection().await;
+
+        let (parts, doc) = action(
+            "insertOne",
+            "application/json",
+            json_body(json!({
+                "dataSource": "mongodb-atlas",
+                "database": db.name(),
+                "collection": collection.name(),
+                "document": {"name": "john", "age": 30},
+            })),
+        )
+        .await;
+
+        assert_eq!(parts.status, StatusCode::CREATED);
+        assert_eq!(parts.headers[header::CONTENT_TYPE], "application/json");
+        assert!(doc.get_object_id("insertedId").is_ok());
+
+        let (parts, doc) = action(
+            "find",
+            "application/json",
+            json_body(json!({
+                "dataSource": "mongodb-atlas",
+                "database": db.name(),
+                "collection": collection.name(),
+                "filter": {"age": {"$gte": 18}},
+                "projection": {"_id": 0},
+            })),
+        )
+        .await;
+
+        assert_eq!(parts.status, StatusCode::OK);
+        assert_eq!(
+            doc.get_array("documents").unwrap()[0]
+                .as_document()
+                .unwrap(),
+            &doc! {"name": "john", "age": 30}
+        );
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn data_api_with_options() {
+        let (db, collection) = get_db_and_collection().await;
+        let id = ObjectId::new();
+
+        let (parts, doc) = action(
+            "updateOne",
+            "application/ejson",
+            get_body_ejson_from_struct(doc! {
+                "dataSource": "mongodb-atlas",
+                "database": db.name(),
+                "collection": collection.name(),
+                "filter": {"_id": id},
+                "update": {"$set": {"name": "jim"}},
+                "upsert": true,
+            }),
+        )
+        .await;
+
+        assert_eq!(parts.status, StatusCode::OK);
+        assert_eq!(parts.headers[header::CONTENT_TYPE], "application/ejson");
+        assert_eq!(doc.get_i64("matchedCount").unwrap(), 0);
+        assert_eq!(doc.get_i64("modifiedCount").unwrap(), 0);
+        assert_eq!(doc.get_object_id("upsertedId").unwrap(), id);
+
+        let (parts, doc) = action(
+            "aggregate",
+            "application/ejson",
+            get_body_ejson_from_struct(doc! {
+                "dataSource": "mongodb-atlas",
+                "database": db.name(),
+                "collection": collection.name(),
+                "pipeline": [{"$project": {"_id": 0, "name": 1}}],
+            }),
+        )
+        .await;
+
+        assert_eq!(parts.status, StatusCode::OK);
+        assert_eq!(
+            doc.get_array("documents").unwrap()[0]
+                .as_document()
+                .unwrap(),
+            &doc! {"name": "jim"}
+        );
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn data_api_data_source() {
+        let (db, collection) = get_db_and_collection().await;
+        let config = crate::helpers::get_config();
+
+        for data_source in ["mongodb-atlas", config.default_data_source.as_str()] {
+            let (parts, doc) = action(
+                "insertOne",
+                "application/json",
+                json_body(json!({
+                    "dataSource": data_source,
+                    "database": db.name(),
+                    "collection": collection.name(),
+                    "document": {"name": "john"},
+                })),
+            )
+            .await;
+
+            assert_eq!(parts.status, StatusCode::CREATED);
+            assert!(doc.get_object_id("insertedId").is_ok());
+        }
+
+        assert_eq!(collection.count_documents(doc! {}).await.unwrap(), 2);
+
+        let (parts, doc) = action(
+            "insertOne",
+            "application/json",
+            json_body(json!({
+                "dataSource": "archive-missing",
+                "database": db.name(),
+                "collection": collection.name(),
+                "document": {"name": "john"},
+            })),
+        )
+        .await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+        assert_eq!(
+            doc,
+            doc! {
+                "error": "Unknown data source: archive-missing",
+                "error_code": "InvalidParameter",
+            }
+        );
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn data_api_error() {
+        let (db, collection) = get_db_and_collection().await;
+
+        let (parts, doc) = action(
+            "updateOne",
+            "application/json",
+            json_body(json!({
+                "database": db.name(),
+                "collection": collection.name(),
+                "filter": {},
+            })),
+        )
+        .await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+        assert_eq!(
+            doc,
+            doc! {
+                "error": "Missing required field: update",
+                "error_code": "MissingParameter",
+            }
+        );
+
+        let (parts, doc) = action(
+            "dropDatabase",
+            "application/json",
+            json_body(json!({
+                "database": db.name(),
+                "collection": collection.name(),
+            })),
+        )
+        .await;
+
+        assert_eq!(parts.status, StatusCode::NOT_FOUND);
+        assert_eq!(
+            doc,
+            doc! {
+                "error": "Unknown action: dropDatabase",
+                "error_code": "ActionNotFound",
+            }
+        );
+
+        let (parts, _) = action(
+            "insertOne",
+            "application/json",
+            json_body(json!({
+                "database": db.name(),
+                "collection": collection.name(),
+                "document": {"_id": 1},
+            })),
+        )
+        .await;
+
+        assert_eq!(parts.status, StatusCode::CREATED);
+
+        let (parts, doc) = action(
+            "insertOne",
+            "application/json",
+            json_body(json!({
+                "database": db.name(),
+                "collection": collection.name(),
+                "document": {"_id": 1},
+            })),
+        )
+        .await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+        assert_eq!(doc.get_str("error_code").unwrap(), "FunctionExecutionError");
+        assert!(doc.get_str("error").unwrap().contains("E11000"));
+
+        let (parts, doc) = action(
+            "find",
+            "text/plain",
+            json_body(json!({
+                "database": db.name(),
+                "collection": collection.name(),
+            })),
+        )
+        .await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+        assert_eq!(doc.get_str("error_code").unwrap(), "InvalidParameter");
+
+        drop_database(&db).await;
+    }
+}
@@ -0,0 +1,144 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        http::{Method, Request, StatusCode},
+    };
+    use mongodb::bson::{doc, Document};
+    use serde::{Deserialize, Serialize};
+    use tower::ServiceExt;
+
+    use crate::helpers::{
+        build_app, drop_database, get_db_and_collection, get_document_from_body, one_shot_document,
+    };
+
+    #[derive(Debug, Deserialize, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct FindOneBody {
+        data_source: Option<String>,
+        db: String,
+        collection: String,
+        filter: Document,
+    }
+
+    #[tokio::test]
+    async fn data_sources() {
+        let (db, collection) = get_db_and_collection().await;
+
+        collection.insert_one(doc! {"name": "john"}).await.unwrap();
+
+        let body = FindOneBody {
+            data_source: None,
+            db: db.name().into(),
+            collection: collection.name().into(),
+            filter: doc! {"name": "john"},
+        };
+
+        let (parts, doc) = one_shot_document("/findOne", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc.get_str("name").unwrap(), "john");
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn data_sources_with_options() {
+        let (db, collection) = get_db_and_collection().await;
+        let config = crate::helpers::get_config();
+
+        collection.insert_one(doc! {"name": "john"}).await.unwrap();
+
+        let body = FindOneBody {
+            data_source: Some(config.default_data_source.clone()),
+            db: db.name().into(),
+            collection: collection.name().into(),
+            filter: doc! {"name": "john"},
+        };
+
+        let (parts, doc) = one_shot_document("/findOne", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc.get_str("name").unwrap(), "john");
+
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri(format!(
+                "/db/{}/{}?dataSource={}",
+                db.name(),
+                collection.name(),
+                config.default_data_source
+            ))
+            .body(Body::empty())
+            .unwrap();
+        let response = build_app().await.oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn data_sources_error() {
+        let (db, collection) = get_db_and_collection().await;
+
+        let body = FindOneBody {
+            data_source: Some("archive-missing".into()),
+            db: db.name().into(),
+            collection: collection.name().into(),
+            filter: doc! {},
+        };
+
+        let (parts, doc) = one_shot_document("/findOne", body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+        assert_eq!(
+            doc.get_str("message").unwrap(),
+            "Unknown data source: archive-missing"
+        );
+
+        let (parts, doc) = one_shot_document(
+            "/findOne",
+            doc! {
+                "dataSource": 5,
+                "db": db.name(),
+                "collection": collection.name(),
+                "filter": {},
+            },
+        )
+        .await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+        assert_eq!(
+            doc.get_str("message").unwrap(),
+            "dataSource must be a string"
+        );
+
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri(format!(
+                "/db/{}/{}?dataSource=archive-missing",
+                db.name(),
+                collection.name()
+            ))
+            .body(Body::empty())
+            .unwrap();
+        let (parts, body) = build_app()
+            .await
+            .oneshot(request)
+            .await
+            .unwrap()
+            .into_parts();
+        let doc = get_document_from_body(body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+        assert_eq!(
+            doc.get_str("message").unwrap(),
+            "Unknown data source: archive-missing"
+        );
+
+        drop_database(&db).await;
+    }
+}
@@ -9,7 +9,9 @@ mod tests {
     };
     use serde::{Deserialize, Serialize};
 
-    use crate::helpers::{get_db_and_collection, get_struct_from_doc, one_shot_document};
+    use crate::helpers::{
+        drop_database, get_db_and_collection, get_struct_from_doc, one_shot_document,
+    };
 
     #[derive(Serialize, Deserialize)]
     struct DeleteManyBody {
@@ -40,7 +42,7 @@ mod tests {
         assert_eq!(parts.status, StatusCode::ACCEPTED);
         assert_eq!(doc.get_i64("deletedCount"), Ok(1));
 
-        db.drop().await.unwrap();
+        drop_database(&db).await;
     }
 
     #[tokio::test]
@@ -66,7 +68,7 @@ mod tests {
         assert_eq!(parts.status, StatusCode::ACCEPTED);
         assert_eq!(doc.get_i64("deletedCount"), Ok(0));
 
-        db.drop().await.unwrap();
+        drop_database(&db).await;
     }
 
     #[tokio::test]
@@ -89,6 +91,6 @@ mod tests {
 
         assert!(error.message.contains("$in needs an array"));
 
-        db.drop().await.unwrap();
+        drop_database(&db).await;
     }
 }
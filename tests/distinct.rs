@@ -0,0 +1,113 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::{
+        bson::{doc, oid::ObjectId, Bson, Document},
+        error::CommandError,
+    };
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{
+        drop_database, get_db_and_collection, get_struct_from_doc, one_shot_array,
+        one_shot_document,
+    };
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct DistinctBody {
+        pub db: String,
+        pub collection: String,
+        pub field_name: String,
+        pub filter: Document,
+        pub options: Option<Document>,
+    }
+
+    #[tokio::test]
+    async fn distinct() {
+        let (db, collection) = get_db_and_collection().await;
+        let user_0 = doc! { "_id": ObjectId::new(), "name": "john", "age": 30 };
+        let user_1 = doc! { "_id": ObjectId::new(), "name": "jim", "age": 30 };
+        let user_2 = doc! { "_id": ObjectId::new(), "name": "jones", "age": 31 };
+
+        collection
+            .insert_many([&user_0, &user_1, &user_2])
+            .await
+            .unwrap();
+
+        let body = DistinctBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            field_name: "age".into(),
+            filter: doc! {},
+            options: None,
+        };
+
+        let (parts, mut values) = one_shot_array("/distinct", body).await;
+
+        values.sort_by_key(|value| value.as_i32());
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(values, vec![Bson::Int32(30), Bson::Int32(31)]);
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn distinct_with_options() {
+        let (db, collection) = get_db_and_collection().await;
+        let user_0 = doc! { "_id": ObjectId::new(), "name": "John", "age": 30 };
+        let user_1 = doc! { "_id": ObjectId::new(), "name": "john", "age": 30 };
+        let user_2 = doc! { "_id": ObjectId::new(), "name": "jim", "age": 31 };
+
+        collection
+            .insert_many([&user_0, &user_1, &user_2])
+            .await
+            .unwrap();
+
+        let body = DistinctBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            field_name: "name".into(),
+            filter: doc! {"age": 30},
+            options: Some(doc! {
+                "collation": {"locale": "en", "strength": 2},
+                "maxTimeMS": 1000,
+            }),
+        };
+
+        let (parts, values) = one_shot_array("/distinct", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(values.len(), 1);
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn distinct_error() {
+        let (db, collection) = get_db_and_collection().await;
+        let user = doc! { "_id": ObjectId::new(), "name": "john", "age": 30 };
+
+        collection.insert_one(&user).await.unwrap();
+
+        let body = DistinctBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            field_name: "age".into(),
+            filter: doc! {"age": doc!{"$in": {}}},
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/distinct", body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+
+        let error = get_struct_from_doc::<CommandError>(doc);
+
+        assert!(error.message.contains("$in needs an array"));
+
+        drop_database(&db).await;
+    }
+}
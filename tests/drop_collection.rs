@@ -0,0 +1,58 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::bson::{doc, Document};
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{drop_database, get_db_and_collection, one_shot_document};
+
+    #[derive(Serialize, Deserialize)]
+    struct DropCollectionBody {
+        pub db: String,
+        pub collection: String,
+        pub options: Option<Document>,
+    }
+
+    #[tokio::test]
+    async fn drop_collection() {
+        let (db, collection) = get_db_and_collection().await;
+
+        collection.insert_one(doc! {"name": "john"}).await.unwrap();
+
+        let body = DropCollectionBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/dropCollection", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc, doc! {});
+        assert!(db.list_collection_names().await.unwrap().is_empty());
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn drop_collection_with_options() {
+        let (db, collection) = get_db_and_collection().await;
+
+        collection.insert_one(doc! {"name": "john"}).await.unwrap();
+
+        let body = DropCollectionBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            options: Some(doc! {"writeConcern": {"w": "majority"}}),
+        };
+
+        let (parts, _) = one_shot_document("/dropCollection", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert!(db.list_collection_names().await.unwrap().is_empty());
+
+        drop_database(&db).await;
+    }
+}
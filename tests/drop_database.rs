@@ -0,0 +1,39 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::bson::{doc, Document};
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{get_db_and_collection, one_shot_document};
+
+    #[derive(Serialize, Deserialize)]
+    struct DropDatabaseBody {
+        pub db: String,
+        pub options: Option<Document>,
+    }
+
+    #[tokio::test]
+    async fn drop_database() {
+        let (db, collection) = get_db_and_collection().await;
+
+        collection.insert_one(doc! {"name": "john"}).await.unwrap();
+
+        let body = DropDatabaseBody {
+            db: db.name().into(),
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/dropDatabase", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc, doc! {});
+        assert!(!db
+            .client()
+            .list_database_names()
+            .await
+            .unwrap()
+            .contains(&db.name().to_string()));
+    }
+}
@@ -0,0 +1,71 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::{
+        bson::{doc, Document},
+        error::CommandError,
+        IndexModel,
+    };
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{
+        drop_database, get_db_and_collection, get_struct_from_doc, one_shot_document,
+    };
+
+    #[derive(Serialize, Deserialize)]
+    struct DropIndexBody {
+        pub db: String,
+        pub collection: String,
+        pub name: String,
+        pub options: Option<Document>,
+    }
+
+    #[tokio::test]
+    async fn drop_index() {
+        let (db, collection) = get_db_and_collection().await;
+        let index = IndexModel::builder().keys(doc! {"name": 1}).build();
+
+        collection.create_index(index).await.unwrap();
+
+        let body = DropIndexBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            name: "name_1".into(),
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/dropIndex", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc, doc! {});
+        assert_eq!(collection.list_index_names().await.unwrap(), vec!["_id_"]);
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn drop_index_error() {
+        let (db, collection) = get_db_and_collection().await;
+
+        collection.insert_one(doc! {"name": "john"}).await.unwrap();
+
+        let body = DropIndexBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            name: "name_1".into(),
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/dropIndex", body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+
+        let error = get_struct_from_doc::<CommandError>(doc);
+
+        assert!(error.message.contains("index not found"));
+
+        drop_database(&db).await;
+    }
+}
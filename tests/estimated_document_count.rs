@@ -0,0 +1,63 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::bson::{doc, oid::ObjectId, Document};
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{drop_database, get_db_and_collection, one_shot_document};
+
+    #[derive(Serialize, Deserialize)]
+    struct EstimatedDocumentCountBody {
+        pub db: String,
+        pub collection: String,
+        pub options: Option<Document>,
+    }
+
+    #[tokio::test]
+    async fn estimated_document_count() {
+        let (db, collection) = get_db_and_collection().await;
+        let user_0 = doc! { "_id": ObjectId::new(), "name": "john", "age": 30 };
+        let user_1 = doc! { "_id": ObjectId::new(), "name": "jim", "age": 31 };
+
+        collection.insert_many([&user_0, &user_1]).await.unwrap();
+
+        let body = EstimatedDocumentCountBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/estimatedDocumentCount", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc.get_i64("count").unwrap(), 2);
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn estimated_document_count_with_options() {
+        let (db, collection) = get_db_and_collection().await;
+        let user = doc! { "_id": ObjectId::new(), "name": "john", "age": 30 };
+
+        collection.insert_one(&user).await.unwrap();
+
+        let body = EstimatedDocumentCountBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            options: Some(doc! {
+                "maxTimeMS": 1000,
+                "comment": "My estimated document count operation",
+            }),
+        };
+
+        let (parts, doc) = one_shot_document("/estimatedDocumentCount", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc.get_i64("count").unwrap(), 1);
+
+        drop_database(&db).await;
+    }
+}
@@ -0,0 +1,109 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::{
+        bson::{doc, oid::ObjectId, Document},
+        error::CommandError,
+    };
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{
+        drop_database, get_db_and_collection, get_struct_from_doc, one_shot_document,
+    };
+
+    #[derive(Serialize, Deserialize)]
+    struct ExplainBody {
+        pub operation: Document,
+        pub verbosity: Option<String>,
+    }
+
+    #[tokio::test]
+    async fn explain() {
+        let (db, collection) = get_db_and_collection().await;
+        let user = doc! { "_id": ObjectId::new(), "name": "john", "age": 30 };
+
+        collection.insert_one(&user).await.unwrap();
+
+        let body = ExplainBody {
+            operation: doc! {"find": {
+                "db": db.name(),
+                "collection": collection.name(),
+                "filter": {"age": 30},
+                "options": {"sort": {"name": 1}, "limit": 5},
+            }},
+            verbosity: Some("executionStats".into()),
+        };
+
+        let (parts, doc) = one_shot_document("/explain", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert!(doc.contains_key("queryPlanner"));
+        assert_eq!(
+            doc.get_document("executionStats")
+                .unwrap()
+                .get_i32("nReturned")
+                .unwrap(),
+            1
+        );
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn explain_update_one() {
+        let (db, collection) = get_db_and_collection().await;
+        let user = doc! { "_id": ObjectId::new(), "name": "john", "age": 30 };
+
+        collection.insert_one(&user).await.unwrap();
+
+        let body = ExplainBody {
+            operation: doc! {"updateOne": {
+                "db": db.name(),
+                "collection": collection.name(),
+                "query": {"name": "john"},
+                "update": {"$set": {"age": 31}},
+            }},
+            verbosity: None,
+        };
+
+        let (parts, doc) = one_shot_document("/explain", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert!(doc.contains_key("queryPlanner"));
+        assert!(!doc.contains_key("executionStats"));
+
+        let stored = collection.find_one(doc! {}).await.unwrap().unwrap();
+
+        assert_eq!(stored, user);
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn explain_error() {
+        let (db, collection) = get_db_and_collection().await;
+
+        collection.insert_one(doc! {"age": 30}).await.unwrap();
+
+        let body = ExplainBody {
+            operation: doc! {"countDocuments": {
+                "db": db.name(),
+                "collection": collection.name(),
+                "filter": {"age": {"$in": {}}},
+            }},
+            verbosity: None,
+        };
+
+        let (parts, doc) = one_shot_document("/explain", body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+
+        let error = get_struct_from_doc::<CommandError>(doc);
+
+        assert!(error.message.contains("$in needs an array"));
+
+        drop_database(&db).await;
+    }
+}
@@ -2,15 +2,21 @@ mod helpers;
 
 #[cfg(test)]
 mod tests {
-    use axum::http::StatusCode;
+    use axum::{
+        body::to_bytes,
+        http::{header, HeaderValue, StatusCode},
+    };
     use mongodb::{
-        bson::{doc, oid::ObjectId, Document},
+        bson::{doc, oid::ObjectId, Bson, Document},
         error::CommandError,
     };
     use serde::{Deserialize, Serialize};
+    use serde_json::Value;
+    use tower::ServiceExt;
 
     use crate::helpers::{
-        get_db_and_collection, get_struct_from_doc, one_shot_array, one_shot_document,
+        build_app, build_request, drop_database, get_body_ejson_from_struct, get_db_and_collection,
+        get_struct_from_doc, one_shot_array, one_shot_document,
     };
 
     #[derive(Serialize, Deserialize)]
@@ -39,10 +45,10 @@ mod tests {
         let (parts, doc) = one_shot_array("/find", body).await;
 
         assert_eq!(parts.status, StatusCode::ACCEPTED);
-        assert_eq!(doc.get(0).unwrap().as_document().unwrap(), &user_0);
+        assert_eq!(doc.first().unwrap().as_document().unwrap(), &user_0);
         assert_eq!(doc.get(1).unwrap().as_document().unwrap(), &user_1);
 
-        db.drop().await.unwrap();
+        drop_database(&db).await;
     }
 
     #[tokio::test]
@@ -74,11 +80,57 @@ mod tests {
         assert_eq!(parts.status, StatusCode::ACCEPTED);
         assert_eq!(doc.len(), 1);
         assert_eq!(
-            doc.get(0).unwrap().as_document().unwrap(),
+            doc.first().unwrap().as_document().unwrap(),
             &doc! {"name": "jim"}
         );
 
-        db.drop().await.unwrap();
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn find_ndjson() {
+        let (db, collection) = get_db_and_collection().await;
+        let user_0 = doc! { "_id": ObjectId::new(), "name": "john", "age": 30 };
+        let user_1 = doc! { "_id": ObjectId::new(), "name": "jim", "age": 31 };
+
+        collection.insert_many([&user_0, &user_1]).await.unwrap();
+
+        let body = FindBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            filter: doc! {},
+            options: Some(doc! {"sort": doc! {"age": 1}, "batchSize": 1}),
+        };
+
+        let mut request = build_request("/find", get_body_ejson_from_struct(body));
+
+        request.headers_mut().insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/x-ndjson"),
+        );
+
+        let response = build_app().await.oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let body_bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let lines: Vec<Document> = std::str::from_utf8(&body_bytes)
+            .unwrap()
+            .lines()
+            .map(|line| {
+                let line_json: Value = serde_json::from_str(line).unwrap();
+                let line_bson: Bson = line_json.try_into().unwrap();
+
+                line_bson.as_document().unwrap().clone()
+            })
+            .collect();
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(
+            parts.headers.get(header::CONTENT_TYPE).unwrap(),
+            "application/x-ndjson"
+        );
+        assert_eq!(lines, vec![user_0, user_1]);
+
+        drop_database(&db).await;
     }
 
     #[tokio::test]
@@ -100,6 +152,6 @@ mod tests {
 
         assert!(error.message.contains("$in needs an array"));
 
-        db.drop().await.unwrap();
+        drop_database(&db).await;
     }
 }
@@ -9,7 +9,9 @@ mod tests {
     };
     use serde::{Deserialize, Serialize};
 
-    use crate::helpers::{get_db_and_collection, get_struct_from_doc, one_shot_document};
+    use crate::helpers::{
+        drop_database, get_db_and_collection, get_struct_from_doc, one_shot_document,
+    };
 
     #[derive(Serialize, Deserialize)]
     struct FindOneBody {
@@ -39,7 +41,7 @@ mod tests {
         assert_eq!(parts.status, StatusCode::ACCEPTED);
         assert_eq!(doc.get_str("name"), user.get_str("name"));
 
-        db.drop().await.unwrap();
+        drop_database(&db).await;
     }
 
     #[tokio::test]
@@ -66,7 +68,7 @@ mod tests {
         assert_eq!(parts.status, StatusCode::ACCEPTED);
         assert_eq!(doc, doc! {"name": "ane"});
 
-        db.drop().await.unwrap();
+        drop_database(&db).await;
     }
 
     #[tokio::test]
@@ -88,6 +90,6 @@ mod tests {
 
         assert!(error.message.contains("$in needs an array"));
 
-        db.drop().await.unwrap();
+        drop_database(&db).await;
     }
 }
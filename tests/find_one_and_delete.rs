@@ -0,0 +1,94 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::{
+        bson::{doc, oid::ObjectId, Document},
+        error::CommandError,
+    };
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{
+        drop_database, get_db_and_collection, get_struct_from_doc, one_shot_document,
+    };
+
+    #[derive(Serialize, Deserialize)]
+    struct FindOneAndDeleteBody {
+        pub db: String,
+        pub collection: String,
+        pub filter: Document,
+        pub options: Option<Document>,
+    }
+
+    #[tokio::test]
+    async fn find_one_and_delete() {
+        let (db, collection) = get_db_and_collection().await;
+        let user = doc! { "_id": ObjectId::new(), "name": "john", "age": 30 };
+
+        collection.insert_one(&user).await.unwrap();
+
+        let body = FindOneAndDeleteBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            filter: doc! {"name": "john"},
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/findOneAndDelete", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc, user);
+        assert_eq!(collection.count_documents(doc! {}).await.unwrap(), 0);
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn find_one_and_delete_with_options() {
+        let (db, collection) = get_db_and_collection().await;
+        let user_0 = doc! { "_id": ObjectId::new(), "name": "john", "age": 30 };
+        let user_1 = doc! { "_id": ObjectId::new(), "name": "jim", "age": 31 };
+
+        collection.insert_many([&user_0, &user_1]).await.unwrap();
+
+        let body = FindOneAndDeleteBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            filter: doc! {},
+            options: Some(doc! {
+                "projection": {"_id": 0, "name": 1},
+                "sort": {"age": -1},
+            }),
+        };
+
+        let (parts, doc) = one_shot_document("/findOneAndDelete", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc, doc! {"name": "jim"});
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn find_one_and_delete_error() {
+        let (db, collection) = get_db_and_collection().await;
+
+        let body = FindOneAndDeleteBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            filter: doc! {"age": doc!{"$in": {}}},
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/findOneAndDelete", body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+
+        let error = get_struct_from_doc::<CommandError>(doc);
+
+        assert!(error.message.contains("$in needs an array"));
+
+        drop_database(&db).await;
+    }
+}
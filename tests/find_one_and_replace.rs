@@ -0,0 +1,90 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::bson::{doc, oid::ObjectId, Document};
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{drop_database, get_db_and_collection, one_shot_document};
+
+    #[derive(Serialize, Deserialize)]
+    struct FindOneAndReplaceBody {
+        pub db: String,
+        pub collection: String,
+        pub filter: Document,
+        pub replacement: Document,
+        pub options: Option<Document>,
+    }
+
+    #[tokio::test]
+    async fn find_one_and_replace() {
+        let (db, collection) = get_db_and_collection().await;
+        let user = doc! { "_id": ObjectId::new(), "name": "john", "age": 30 };
+
+        collection.insert_one(&user).await.unwrap();
+
+        let body = FindOneAndReplaceBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            filter: doc! {"name": "john"},
+            replacement: doc! {"name": "jim"},
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/findOneAndReplace", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc, user);
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn find_one_and_replace_with_options() {
+        let (db, collection) = get_db_and_collection().await;
+        let id = ObjectId::new();
+
+        let body = FindOneAndReplaceBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            filter: doc! {"_id": id},
+            replacement: doc! {"name": "jim"},
+            options: Some(doc! {
+                "projection": {"_id": 1, "name": 1},
+                "returnDocument": "After",
+                "upsert": true,
+            }),
+        };
+
+        let (parts, doc) = one_shot_document("/findOneAndReplace", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc, doc! {"_id": id, "name": "jim"});
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn find_one_and_replace_error() {
+        let (db, collection) = get_db_and_collection().await;
+
+        let body = FindOneAndReplaceBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            filter: doc! {"name": "john"},
+            replacement: doc! {"$set": {"name": "jim"}},
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/findOneAndReplace", body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+        assert!(doc
+            .get_str("message")
+            .unwrap()
+            .contains("must not contain update operators"));
+
+        drop_database(&db).await;
+    }
+}
@@ -0,0 +1,123 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::{body::to_bytes, http::StatusCode};
+    use mongodb::{
+        bson::{doc, oid::ObjectId, Document},
+        error::CommandError,
+    };
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{
+        drop_database, get_db_and_collection, get_struct_from_doc, one_shot, one_shot_document,
+    };
+
+    #[derive(Serialize, Deserialize)]
+    struct FindOneAndUpdateBody {
+        pub db: String,
+        pub collection: String,
+        pub filter: Document,
+        pub update: Document,
+        pub options: Option<Document>,
+    }
+
+    #[tokio::test]
+    async fn find_one_and_update() {
+        let (db, collection) = get_db_and_collection().await;
+        let user = doc! { "_id": ObjectId::new(), "name": "john", "age": 30 };
+
+        collection.insert_one(&user).await.unwrap();
+
+        let body = FindOneAndUpdateBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            filter: doc! {"name": "john"},
+            update: doc! {"$inc": {"age": 1}},
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/findOneAndUpdate", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc, user);
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn find_one_and_update_with_options() {
+        let (db, collection) = get_db_and_collection().await;
+        let user_0 = doc! { "_id": ObjectId::new(), "name": "john", "age": 30, "tags": ["a", "b"] };
+        let user_1 = doc! { "_id": ObjectId::new(), "name": "jim", "age": 31, "tags": ["a", "b"] };
+
+        collection.insert_many([&user_0, &user_1]).await.unwrap();
+
+        let body = FindOneAndUpdateBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            filter: doc! {},
+            update: doc! {"$set": {"tags.$[tag]": "c"}},
+            options: Some(doc! {
+                "arrayFilters": [{"tag": "b"}],
+                "projection": {"_id": 0, "name": 1, "tags": 1},
+                "returnDocument": "After",
+                "sort": {"age": -1},
+            }),
+        };
+
+        let (parts, doc) = one_shot_document("/findOneAndUpdate", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc, doc! {"name": "jim", "tags": ["a", "c"]});
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn find_one_and_update_no_match() {
+        let (db, collection) = get_db_and_collection().await;
+
+        let body = FindOneAndUpdateBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            filter: doc! {"name": "john"},
+            update: doc! {"$inc": {"age": 1}},
+            options: None,
+        };
+
+        let (parts, body) = one_shot("/findOneAndUpdate", body).await;
+        let body_bytes = to_bytes(body, usize::MAX).await.unwrap();
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(&body_bytes[..], b"null");
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn find_one_and_update_error() {
+        let (db, collection) = get_db_and_collection().await;
+        let user = doc! { "_id": ObjectId::new(), "name": "john", "age": 30 };
+
+        collection.insert_one(&user).await.unwrap();
+
+        let body = FindOneAndUpdateBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            filter: doc! {"name": "john"},
+            update: doc! {"$setOrInsert": {"age": 1}},
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/findOneAndUpdate", body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+
+        let error = get_struct_from_doc::<CommandError>(doc);
+
+        assert!(error.message.contains("Unknown modifier"));
+
+        drop_database(&db).await;
+    }
+}
@@ -0,0 +1,116 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::bson::{doc, Document};
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{
+        build_app, drop_database, get_db_and_collection, one_shot_document, one_shot_document_with,
+    };
+
+    #[derive(Serialize, Deserialize)]
+    struct FindBody {
+        pub db: String,
+        pub collection: String,
+        pub filter: Document,
+        pub options: Option<Document>,
+        pub cursor: Option<bool>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct GetMoreBody {
+        pub cursor_id: String,
+        pub batch_size: Option<u32>,
+    }
+
+    #[tokio::test]
+    async fn get_more() {
+        let (db, collection) = get_db_and_collection().await;
+        let users: Vec<Document> = (0..5).map(|age| doc! {"_id": age, "age": age}).collect();
+
+        collection.insert_many(&users).await.unwrap();
+
+        let app_router = build_app().await;
+
+        let body = FindBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            filter: doc! {},
+            options: Some(doc! {"sort": {"age": 1}, "batchSize": 2}),
+            cursor: Some(true),
+        };
+
+        let (parts, doc) = one_shot_document_with(&app_router, "/find", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc.get_array("batch").unwrap().len(), 2);
+
+        let cursor_id = doc.get_str("cursorId").unwrap().to_string();
+
+        let body = GetMoreBody {
+            cursor_id: cursor_id.clone(),
+            batch_size: Some(2),
+        };
+
+        let (parts, doc) = one_shot_document_with(&app_router, "/getMore", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc.get_str("cursorId").unwrap(), cursor_id);
+        assert_eq!(
+            doc.get_array("batch").unwrap().first().unwrap(),
+            &doc! {"_id": 2, "age": 2}.into()
+        );
+
+        let body = GetMoreBody {
+            cursor_id: cursor_id.clone(),
+            batch_size: None,
+        };
+
+        let (parts, doc) = one_shot_document_with(&app_router, "/getMore", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert!(doc.is_null("cursorId"));
+        assert_eq!(doc.get_array("batch").unwrap().len(), 1);
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn get_more_exhausted_first_batch() {
+        let (db, collection) = get_db_and_collection().await;
+
+        collection.insert_one(doc! {"age": 30}).await.unwrap();
+
+        let body = FindBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            filter: doc! {},
+            options: None,
+            cursor: Some(true),
+        };
+
+        let (parts, doc) = one_shot_document("/find", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert!(doc.is_null("cursorId"));
+        assert_eq!(doc.get_array("batch").unwrap().len(), 1);
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn get_more_error() {
+        let body = GetMoreBody {
+            cursor_id: "unknown".into(),
+            batch_size: None,
+        };
+
+        let (parts, doc) = one_shot_document("/getMore", body).await;
+
+        assert_eq!(parts.status, StatusCode::NOT_FOUND);
+        assert_eq!(doc.get_str("message").unwrap(), "Cursor not found: unknown");
+    }
+}
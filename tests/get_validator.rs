@@ -0,0 +1,87 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::{
+        bson::{doc, Bson},
+        options::{ValidationAction, ValidationLevel},
+    };
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{drop_database, get_db_and_collection, one_shot_document};
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct GetValidatorBody {
+        db: String,
+        collection: String,
+    }
+
+    #[tokio::test]
+    async fn get_validator() {
+        let (db, collection) = get_db_and_collection().await;
+        let validator = doc! {"$jsonSchema": {"required": ["name"]}};
+
+        db.create_collection(collection.name())
+            .validator(validator.clone())
+            .await
+            .unwrap();
+
+        let body = GetValidatorBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+        };
+
+        let (parts, doc) = one_shot_document("/getValidator", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc.get_document("validator").unwrap(), &validator);
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn get_validator_with_options() {
+        let (db, collection) = get_db_and_collection().await;
+
+        db.create_collection(collection.name())
+            .validator(doc! {"age": {"$gte": 0}})
+            .validation_level(ValidationLevel::Moderate)
+            .validation_action(ValidationAction::Warn)
+            .await
+            .unwrap();
+
+        let body = GetValidatorBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+        };
+
+        let (parts, doc) = one_shot_document("/getValidator", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc.get_str("validationLevel").unwrap(), "moderate");
+        assert_eq!(doc.get_str("validationAction").unwrap(), "warn");
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn get_validator_error() {
+        let (db, _) = get_db_and_collection().await;
+
+        let body = GetValidatorBody {
+            db: db.name().into(),
+            collection: "missing".into(),
+        };
+
+        let (parts, doc) = one_shot_document("/getValidator", body).await;
+
+        assert_eq!(parts.status, StatusCode::NOT_FOUND);
+        assert_eq!(
+            doc.get("message"),
+            Some(&Bson::String("Collection not found: missing".into()))
+        );
+
+        drop_database(&db).await;
+    }
+}
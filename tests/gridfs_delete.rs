@@ -0,0 +1,86 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::bson::{doc, Bson, Document};
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{drop_database, get_db_and_collection, one_shot_document, upload_file};
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct DeleteFileBody {
+        db: String,
+        bucket: Option<String>,
+        id: Bson,
+    }
+
+    #[tokio::test]
+    async fn gridfs_delete() {
+        let (db, _) = get_db_and_collection().await;
+        let id = upload_file(&db, "notes.txt", b"hello gridfs", doc! {}).await;
+
+        let body = DeleteFileBody {
+            db: db.name().into(),
+            bucket: None,
+            id: id.clone(),
+        };
+
+        let (parts, doc) = one_shot_document("/gridfs/delete", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc, doc! {});
+        assert_eq!(
+            db.collection::<Document>("fs.chunks")
+                .count_documents(doc! {"files_id": id})
+                .await
+                .unwrap(),
+            0
+        );
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn gridfs_delete_with_options() {
+        let (db, _) = get_db_and_collection().await;
+        let id = upload_file(&db, "notes.txt", b"hello gridfs", doc! {}).await;
+
+        let body = DeleteFileBody {
+            db: db.name().into(),
+            bucket: Some("fs".into()),
+            id: id.clone(),
+        };
+
+        let (parts, _) = one_shot_document("/gridfs/delete", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(
+            db.collection::<Document>("fs.files")
+                .count_documents(doc! {"_id": id})
+                .await
+                .unwrap(),
+            0
+        );
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn gridfs_delete_error() {
+        let (db, _) = get_db_and_collection().await;
+
+        let body = DeleteFileBody {
+            db: db.name().into(),
+            bucket: None,
+            id: Bson::String("missing".into()),
+        };
+
+        let (parts, doc) = one_shot_document("/gridfs/delete", body).await;
+
+        assert_eq!(parts.status, StatusCode::NOT_FOUND);
+        assert!(doc.get_str("message").unwrap().contains("missing"));
+
+        drop_database(&db).await;
+    }
+}
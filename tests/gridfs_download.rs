@@ -0,0 +1,106 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::{to_bytes, Body},
+        http::{header, Method, Request, StatusCode},
+    };
+    use mongodb::bson::doc;
+    use tower::ServiceExt;
+
+    use crate::helpers::{
+        build_app, drop_database, get_db_and_collection, get_document_from_body, upload_file,
+    };
+
+    fn build_download_request(uri: &str) -> Request<Body> {
+        Request::builder()
+            .method(Method::GET)
+            .uri(uri)
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn gridfs_download() {
+        let (db, _) = get_db_and_collection().await;
+        let id = upload_file(
+            &db,
+            "notes.txt",
+            b"hello gridfs",
+            doc! {"contentType": "text/plain"},
+        )
+        .await;
+        let uri = format!(
+            "/gridfs/download?db={}&id={}",
+            db.name(),
+            id.as_object_id().unwrap().to_hex()
+        );
+
+        let response = build_app()
+            .await
+            .oneshot(build_download_request(&uri))
+            .await
+            .unwrap();
+        let (parts, body) = response.into_parts();
+
+        assert_eq!(parts.status, StatusCode::OK);
+        assert_eq!(parts.headers[header::CONTENT_TYPE], "text/plain");
+        assert_eq!(parts.headers[header::CONTENT_LENGTH], "12");
+        assert_eq!(
+            to_bytes(body, usize::MAX).await.unwrap().as_ref(),
+            b"hello gridfs"
+        );
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn gridfs_download_with_options() {
+        let (db, _) = get_db_and_collection().await;
+
+        upload_file(&db, "notes.txt", b"first", doc! {}).await;
+        upload_file(&db, "notes.txt", b"second", doc! {}).await;
+
+        let uri = format!("/gridfs/download?db={}&filename=notes.txt", db.name());
+
+        let response = build_app()
+            .await
+            .oneshot(build_download_request(&uri))
+            .await
+            .unwrap();
+        let (parts, body) = response.into_parts();
+
+        assert_eq!(parts.status, StatusCode::OK);
+        assert_eq!(
+            parts.headers[header::CONTENT_TYPE],
+            "application/octet-stream"
+        );
+        assert_eq!(parts.headers[header::CONTENT_LENGTH], "6");
+        assert_eq!(
+            to_bytes(body, usize::MAX).await.unwrap().as_ref(),
+            b"second"
+        );
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn gridfs_download_error() {
+        let (db, _) = get_db_and_collection().await;
+        let uri = format!("/gridfs/download?db={}&filename=missing.txt", db.name());
+
+        let response = build_app()
+            .await
+            .oneshot(build_download_request(&uri))
+            .await
+            .unwrap();
+        let (parts, body) = response.into_parts();
+        let doc = get_document_from_body(body).await;
+
+        assert_eq!(parts.status, StatusCode::NOT_FOUND);
+        assert_eq!(doc.get_str("message").unwrap(), "File not found");
+
+        drop_database(&db).await;
+    }
+}
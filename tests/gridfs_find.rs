@@ -0,0 +1,93 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::bson::{doc, Document};
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{
+        drop_database, get_db_and_collection, one_shot_array, one_shot_document, upload_file,
+    };
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct FindFilesBody {
+        db: String,
+        bucket: Option<String>,
+        filter: Document,
+        options: Option<Document>,
+    }
+
+    #[tokio::test]
+    async fn gridfs_find() {
+        let (db, _) = get_db_and_collection().await;
+        let id = upload_file(&db, "notes.txt", b"hello gridfs", doc! {"owner": "john"}).await;
+
+        upload_file(&db, "other.txt", b"other", doc! {"owner": "jim"}).await;
+
+        let body = FindFilesBody {
+            db: db.name().into(),
+            bucket: None,
+            filter: doc! {"metadata.owner": "john"},
+            options: None,
+        };
+
+        let (parts, files) = one_shot_array("/gridfs/find", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(files.len(), 1);
+
+        let file = files[0].as_document().unwrap();
+
+        assert_eq!(file.get("_id"), Some(&id));
+        assert_eq!(file.get_str("filename").unwrap(), "notes.txt");
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn gridfs_find_with_options() {
+        let (db, _) = get_db_and_collection().await;
+
+        upload_file(&db, "a.txt", b"a", doc! {}).await;
+        upload_file(&db, "b.txt", b"b", doc! {}).await;
+        upload_file(&db, "c.txt", b"c", doc! {}).await;
+
+        let body = FindFilesBody {
+            db: db.name().into(),
+            bucket: Some("fs".into()),
+            filter: doc! {},
+            options: Some(doc! {"sort": {"filename": -1}, "skip": 1, "limit": 1}),
+        };
+
+        let (parts, files) = one_shot_array("/gridfs/find", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(files.len(), 1);
+        assert_eq!(
+            files[0].as_document().unwrap().get_str("filename").unwrap(),
+            "b.txt"
+        );
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn gridfs_find_error() {
+        let (db, _) = get_db_and_collection().await;
+
+        let body = FindFilesBody {
+            db: db.name().into(),
+            bucket: None,
+            filter: doc! {"$invalid": 1},
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/gridfs/find", body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+        assert!(doc.get_str("errmsg").unwrap().contains("$invalid"));
+
+        drop_database(&db).await;
+    }
+}
@@ -0,0 +1,184 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        http::{header, Method, Request, StatusCode},
+    };
+    use futures::AsyncReadExt;
+    use mongodb::bson::doc;
+    use tower::ServiceExt;
+
+    use crate::helpers::{build_app, drop_database, get_db_and_collection, get_document_from_body};
+
+    fn build_upload_request(uri: &str, contents: &'static [u8]) -> Request<Body> {
+        Request::builder()
+            .method(Method::POST)
+            .uri(uri)
+            .header(header::CONTENT_TYPE, "text/plain")
+            .body(Body::from(contents))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn gridfs_upload() {
+        let (db, _) = get_db_and_collection().await;
+        let uri = format!("/gridfs/upload?db={}&filename=notes.txt", db.name());
+        let request = build_upload_request(&uri, b"hello gridfs");
+
+        let response = build_app().await.oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let doc = get_document_from_body(body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+
+        let bucket = db.gridfs_bucket(None);
+        let file = bucket
+            .find_one(doc! {"_id": doc.get("id").unwrap()})
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(file.filename.as_deref(), Some("notes.txt"));
+        assert_eq!(file.length, 12);
+        assert_eq!(
+            file.metadata.unwrap().get_str("contentType").unwrap(),
+            "text/plain"
+        );
+
+        let mut contents = Vec::new();
+        let mut download = bucket.open_download_stream(file.id).await.unwrap();
+
+        download.read_to_end(&mut contents).await.unwrap();
+
+        assert_eq!(contents, b"hello gridfs");
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn gridfs_upload_with_options() {
+        let (db, _) = get_db_and_collection().await;
+        let uri = format!(
+            "/gridfs/upload?db={}&bucket=avatars&filename=notes.txt&id=notes&chunkSizeBytes=4&metadata=%7B%22owner%22%3A%22john%22%7D",
+            db.name()
+        );
+        let request = build_upload_request(&uri, b"hello gridfs");
+
+        let response = build_app().await.oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let doc = get_document_from_body(body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc, doc! {"id": "notes"});
+
+        let file = db
+            .collection::<mongodb::bson::Document>("avatars.files")
+            .find_one(doc! {"_id": "notes"})
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(file.get_i32("chunkSize").unwrap(), 4);
+        assert_eq!(
+            file.get_document("metadata").unwrap(),
+            &doc! {"owner": "john", "contentType": "text/plain"}
+        );
+        assert_eq!(
+            db.collection::<mongodb::bson::Document>("avatars.chunks")
+                .count_documents(doc! {"files_id": "notes"})
+                .await
+                .unwrap(),
+            3
+        );
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn gridfs_upload_error() {
+        let (db, _) = get_db_and_collection().await;
+        let uri = format!(
+            "/gridfs/upload?db={}&filename=notes.txt&metadata=%5B1%5D",
+            db.name()
+        );
+        let request = build_upload_request(&uri, b"hello gridfs");
+
+        let response = build_app().await.oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let doc = get_document_from_body(body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+        assert_eq!(
+            doc.get_str("message").unwrap(),
+            "Query parameter metadata must be an EJSON document"
+        );
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn gridfs_upload_multipart() {
+        let (db, _) = get_db_and_collection().await;
+        let uri = format!(
+            "/gridfs/upload?db={}&metadata=%7B%22owner%22%3A%22john%22%7D",
+            db.name()
+        );
+        let body = "--X\r\n\
+            Content-Disposition: form-data; name=\"note\"\r\n\r\n\
+            not a file\r\n\
+            --X\r\n\
+            Content-Disposition: form-data; name=\"file\"; filename=\"notes.txt\"\r\n\
+            Content-Type: text/plain\r\n\r\n\
+            hello gridfs\r\n\
+            --X\r\n\
+            Content-Disposition: form-data; name=\"file\"; filename=\"data.bin\"\r\n\r\n\
+            \x01\x02\r\n\
+            --X--\r\n";
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(uri)
+            .header(header::CONTENT_TYPE, "multipart/form-data; boundary=X")
+            .body(Body::from(body))
+            .unwrap();
+
+        let response = build_app().await.oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let doc = get_document_from_body(body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+
+        let ids = doc.get_array("ids").unwrap();
+        let bucket = db.gridfs_bucket(None);
+
+        assert_eq!(ids.len(), 2);
+
+        for (id, filename, contents, content_type) in [
+            (&ids[0], "notes.txt", &b"hello gridfs"[..], "text/plain"),
+            (
+                &ids[1],
+                "data.bin",
+                &b"\x01\x02"[..],
+                "application/octet-stream",
+            ),
+        ] {
+            let file = bucket.find_one(doc! {"_id": id}).await.unwrap().unwrap();
+
+            assert_eq!(file.filename.as_deref(), Some(filename));
+            assert_eq!(
+                file.metadata.unwrap(),
+                doc! {"owner": "john", "contentType": content_type}
+            );
+
+            let mut stored = Vec::new();
+            let mut download = bucket.open_download_stream(file.id).await.unwrap();
+
+            download.read_to_end(&mut stored).await.unwrap();
+
+            assert_eq!(stored, contents);
+        }
+
+        drop_database(&db).await;
+    }
+}
@@ -2,21 +2,35 @@
 
 use axum::{
     body::{to_bytes, Body},
-    http::{header, response::Parts, Method, Request},
+    http::{header, response::Parts, Method, Request, StatusCode},
+    Router,
 };
+use futures::AsyncWriteExt;
 use mongodb::{
     bson::{self, doc, oid::ObjectId, Array, Bson, Document},
+    options::GridFsUploadOptions,
     Collection, Database,
 };
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::Value;
 use tower::ServiceExt;
 
-use rs_data_api::{app, mdb};
+use rs_data_api::{app, config::Config, mdb};
+
+pub fn get_config() -> Config {
+    Config::load().unwrap()
+}
+
+pub async fn build_app() -> Router {
+    app::build(&get_config()).await.unwrap()
+}
 
 pub async fn get_db_and_collection() -> (Database, Collection<Document>) {
-    let client = mdb::get_client().await;
-    let db = client.database(&format!("test-{}", ObjectId::new().to_string()));
+    let config = get_config();
+    let client = mdb::get_client(&config.data_sources[&config.default_data_source])
+        .await
+        .unwrap();
+    let db = client.database(&format!("test-{}", ObjectId::new()));
     let collection = db.collection::<Document>("documents");
 
     collection.delete_many(doc! {}).await.unwrap();
@@ -24,6 +38,34 @@ pub async fn get_db_and_collection() -> (Database, Collection<Document>) {
     (db, collection)
 }
 
+/// Drops a test database through `/dropDatabase`, so cleanup exercises the
+/// endpoint instead of reaching around it with the driver.
+pub async fn drop_database(db: &Database) {
+    let (parts, _) = one_shot("/dropDatabase", doc! {"db": db.name()}).await;
+
+    assert_eq!(parts.status, StatusCode::ACCEPTED);
+}
+
+pub async fn upload_file(
+    db: &Database,
+    filename: &str,
+    contents: &[u8],
+    metadata: Document,
+) -> Bson {
+    let options = GridFsUploadOptions::builder().metadata(metadata).build();
+    let mut upload = db
+        .gridfs_bucket(None)
+        .open_upload_stream(filename)
+        .with_options(options)
+        .await
+        .unwrap();
+
+    upload.write_all(contents).await.unwrap();
+    upload.close().await.unwrap();
+
+    upload.id().clone()
+}
+
 pub fn get_body_ejson_from_struct(structure: impl Serialize) -> Body {
     let structure_json = structure
         .serialize(bson::Serializer::new())
@@ -65,12 +107,36 @@ pub fn build_request(uri: &str, body: Body) -> Request<Body> {
 pub async fn one_shot(uri: &str, body: impl Serialize) -> (Parts, Body) {
     let body_ejson = get_body_ejson_from_struct(body);
     let request = build_request(uri, body_ejson);
-    let app_router = app::build().await;
+    let app_router = build_app().await;
     let (parts, body) = app_router.oneshot(request).await.unwrap().into_parts();
 
     (parts, body)
 }
 
+pub async fn one_shot_with(app_router: &Router, uri: &str, body: impl Serialize) -> (Parts, Body) {
+    let body_ejson = get_body_ejson_from_struct(body);
+    let request = build_request(uri, body_ejson);
+    let (parts, body) = app_router
+        .clone()
+        .oneshot(request)
+        .await
+        .unwrap()
+        .into_parts();
+
+    (parts, body)
+}
+
+pub async fn one_shot_document_with(
+    app_router: &Router,
+    uri: &str,
+    body: impl Serialize,
+) -> (Parts, Document) {
+    let (parts, body) = one_shot_with(app_router, uri, body).await;
+    let doc = get_document_from_body(body).await;
+
+    (parts, doc)
+}
+
 pub async fn one_shot_document(uri: &str, body: impl Serialize) -> (Parts, Document) {
     let (parts, body) = one_shot(uri, body).await;
     let doc = get_document_from_body(body).await;
@@ -6,7 +6,7 @@ mod tests {
     use mongodb::bson::{doc, oid::ObjectId, Document};
     use serde::{Deserialize, Serialize};
 
-    use crate::helpers::{get_db_and_collection, one_shot_document};
+    use crate::helpers::{drop_database, get_db_and_collection, one_shot_document};
 
     #[derive(Debug, Deserialize, Serialize)]
     pub struct InsertManyBody {
@@ -42,7 +42,7 @@ mod tests {
             user_1.get("_id")
         );
 
-        db.drop().await.unwrap();
+        drop_database(&db).await;
     }
 
     #[tokio::test]
@@ -80,7 +80,7 @@ mod tests {
             user_1.get("_id")
         );
 
-        db.drop().await.unwrap();
+        drop_database(&db).await;
     }
 
     #[tokio::test]
@@ -107,7 +107,7 @@ mod tests {
         let error_message = doc
             .get_array("writeErrors")
             .unwrap()
-            .get(0)
+            .first()
             .unwrap()
             .as_document()
             .unwrap()
@@ -116,6 +116,6 @@ mod tests {
 
         assert!(error_message.contains("E11000 duplicate key error"));
 
-        db.drop().await.unwrap();
+        drop_database(&db).await;
     }
 }
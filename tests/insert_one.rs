@@ -9,7 +9,9 @@ mod tests {
     };
     use serde::{Deserialize, Serialize};
 
-    use crate::helpers::{get_db_and_collection, get_struct_from_doc, one_shot_document};
+    use crate::helpers::{
+        drop_database, get_db_and_collection, get_struct_from_doc, one_shot_document,
+    };
 
     #[derive(Debug, Serialize, Deserialize)]
     struct InsertOneBody {
@@ -36,7 +38,7 @@ mod tests {
         assert_eq!(parts.status, StatusCode::ACCEPTED);
         assert_eq!(doc.get("inserted_id"), user.get("_id"));
 
-        db.drop().await.unwrap();
+        drop_database(&db).await;
     }
 
     #[tokio::test]
@@ -64,7 +66,7 @@ mod tests {
         assert_eq!(parts.status, StatusCode::ACCEPTED);
         assert_eq!(doc.get("inserted_id"), user.get("_id"));
 
-        db.drop().await.unwrap();
+        drop_database(&db).await;
     }
 
     #[tokio::test]
@@ -93,6 +95,6 @@ mod tests {
             panic!("wrong")
         }
 
-        db.drop().await.unwrap();
+        drop_database(&db).await;
     }
 }
@@ -0,0 +1,130 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::bson::{doc, Bson, Document};
+    use serde::{Deserialize, Serialize};
+    use std::time::Duration;
+
+    use crate::helpers::{build_app, drop_database, get_db_and_collection, one_shot_document_with};
+
+    #[derive(Serialize, Deserialize)]
+    struct AggregateBody {
+        pub db: String,
+        pub collection: String,
+        pub pipeline: Vec<Document>,
+        pub options: Option<Document>,
+        pub cursor: Option<bool>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct KillCursorsBody {
+        pub cursor_ids: Vec<String>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct GetMoreBody {
+        pub cursor_id: String,
+    }
+
+    #[tokio::test]
+    async fn kill_cursors() {
+        let (db, collection) = get_db_and_collection().await;
+        let users: Vec<Document> = (0..5).map(|age| doc! {"age": age}).collect();
+
+        collection.insert_many(&users).await.unwrap();
+
+        let app_router = build_app().await;
+
+        let body = AggregateBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            pipeline: vec![doc! {"$sort": {"age": 1}}],
+            options: Some(doc! {"batchSize": 1}),
+            cursor: Some(true),
+        };
+
+        let (parts, doc) = one_shot_document_with(&app_router, "/aggregate", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+
+        let cursor_id = doc.get_str("cursorId").unwrap().to_string();
+
+        let body = KillCursorsBody {
+            cursor_ids: vec![cursor_id.clone(), "unknown".into()],
+        };
+
+        let (parts, doc) = one_shot_document_with(&app_router, "/killCursors", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(
+            doc.get_array("cursorsKilled").unwrap(),
+            &vec![Bson::from(cursor_id.clone())]
+        );
+        assert_eq!(
+            doc.get_array("cursorsNotFound").unwrap(),
+            &vec![Bson::from("unknown")]
+        );
+
+        let body = GetMoreBody { cursor_id };
+
+        let (parts, _) = one_shot_document_with(&app_router, "/getMore", body).await;
+
+        assert_eq!(parts.status, StatusCode::NOT_FOUND);
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn kill_cursors_after_dropped_get_more() {
+        let (db, collection) = get_db_and_collection().await;
+        let users: Vec<Document> = (0..5).map(|age| doc! {"age": age}).collect();
+
+        collection.insert_many(&users).await.unwrap();
+
+        let app_router = build_app().await;
+
+        let body = AggregateBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            pipeline: vec![doc! {"$sort": {"age": 1}}],
+            options: Some(doc! {"batchSize": 1}),
+            cursor: Some(true),
+        };
+
+        let (_, doc) = one_shot_document_with(&app_router, "/aggregate", body).await;
+        let cursor_id = doc.get_str("cursorId").unwrap().to_string();
+
+        // The getMore has to wait on the server, so a zero timeout drops it
+        // mid-flight, like a client disconnecting.
+        let get_more = one_shot_document_with(
+            &app_router,
+            "/getMore",
+            GetMoreBody {
+                cursor_id: cursor_id.clone(),
+            },
+        );
+
+        assert!(tokio::time::timeout(Duration::ZERO, get_more)
+            .await
+            .is_err());
+
+        let body = KillCursorsBody {
+            cursor_ids: vec![cursor_id.clone()],
+        };
+
+        let (parts, doc) = one_shot_document_with(&app_router, "/killCursors", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert!(doc.get_array("cursorsAlive").unwrap().is_empty());
+        assert_eq!(
+            doc.get_array("cursorsNotFound").unwrap(),
+            &vec![Bson::from(cursor_id)]
+        );
+
+        drop_database(&db).await;
+    }
+}
@@ -0,0 +1,67 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::bson::{doc, Bson, Document};
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{drop_database, get_db_and_collection, one_shot_array};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct ListCollectionsBody {
+        pub db: String,
+        pub filter: Option<Document>,
+        pub name_only: Option<bool>,
+        pub options: Option<Document>,
+    }
+
+    #[tokio::test]
+    async fn list_collections() {
+        let (db, collection) = get_db_and_collection().await;
+
+        collection.insert_one(doc! {"name": "john"}).await.unwrap();
+
+        let body = ListCollectionsBody {
+            db: db.name().into(),
+            filter: None,
+            name_only: None,
+            options: None,
+        };
+
+        let (parts, collections) = one_shot_array("/listCollections", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(collections.len(), 1);
+
+        let specification = collections.first().unwrap().as_document().unwrap();
+
+        assert_eq!(specification.get_str("name").unwrap(), collection.name());
+        assert_eq!(specification.get_str("type").unwrap(), "collection");
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn list_collections_name_only() {
+        let (db, collection) = get_db_and_collection().await;
+
+        collection.insert_one(doc! {"name": "john"}).await.unwrap();
+        db.create_collection("users").await.unwrap();
+
+        let body = ListCollectionsBody {
+            db: db.name().into(),
+            filter: Some(doc! {"name": "users"}),
+            name_only: Some(true),
+            options: None,
+        };
+
+        let (parts, collections) = one_shot_array("/listCollections", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(collections, vec![Bson::from("users")]);
+
+        drop_database(&db).await;
+    }
+}
@@ -0,0 +1,63 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::bson::{doc, Bson, Document};
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{drop_database, get_db_and_collection, one_shot_array};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct ListDatabasesBody {
+        pub filter: Option<Document>,
+        pub name_only: Option<bool>,
+        pub options: Option<Document>,
+    }
+
+    #[tokio::test]
+    async fn list_databases() {
+        let (db, collection) = get_db_and_collection().await;
+
+        collection.insert_one(doc! {"name": "john"}).await.unwrap();
+
+        let body = ListDatabasesBody {
+            filter: Some(doc! {"name": db.name()}),
+            name_only: None,
+            options: None,
+        };
+
+        let (parts, databases) = one_shot_array("/listDatabases", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(databases.len(), 1);
+
+        let database = databases.first().unwrap().as_document().unwrap();
+
+        assert_eq!(database.get_str("name").unwrap(), db.name());
+        assert!(database.contains_key("sizeOnDisk"));
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn list_databases_name_only() {
+        let (db, collection) = get_db_and_collection().await;
+
+        collection.insert_one(doc! {"name": "john"}).await.unwrap();
+
+        let body = ListDatabasesBody {
+            filter: Some(doc! {"name": db.name()}),
+            name_only: Some(true),
+            options: None,
+        };
+
+        let (parts, databases) = one_shot_array("/listDatabases", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(databases, vec![Bson::from(db.name())]);
+
+        drop_database(&db).await;
+    }
+}
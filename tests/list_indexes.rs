@@ -0,0 +1,74 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::{
+        bson::{doc, Document},
+        options::IndexOptions,
+        IndexModel,
+    };
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{drop_database, get_db_and_collection, one_shot_array};
+
+    #[derive(Serialize, Deserialize)]
+    struct ListIndexesBody {
+        pub db: String,
+        pub collection: String,
+        pub options: Option<Document>,
+    }
+
+    #[tokio::test]
+    async fn list_indexes() {
+        let (db, collection) = get_db_and_collection().await;
+        let index = IndexModel::builder()
+            .keys(doc! {"name": 1})
+            .options(IndexOptions::builder().unique(true).build())
+            .build();
+
+        collection.create_index(index).await.unwrap();
+
+        let body = ListIndexesBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            options: None,
+        };
+
+        let (parts, indexes) = one_shot_array("/listIndexes", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(indexes.len(), 2);
+
+        let name_index = indexes.get(1).unwrap().as_document().unwrap();
+
+        assert_eq!(name_index.get_document("key").unwrap(), &doc! {"name": 1});
+        assert_eq!(name_index.get_str("name").unwrap(), "name_1");
+        assert!(name_index.get_bool("unique").unwrap());
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn list_indexes_with_options() {
+        let (db, collection) = get_db_and_collection().await;
+
+        collection.insert_one(doc! {"name": "john"}).await.unwrap();
+
+        let body = ListIndexesBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            options: Some(doc! {
+                "maxTimeMS": 1000,
+                "comment": "My list indexes operation",
+            }),
+        };
+
+        let (parts, indexes) = one_shot_array("/listIndexes", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(indexes.len(), 1);
+
+        drop_database(&db).await;
+    }
+}
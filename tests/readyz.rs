@@ -0,0 +1,103 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::{to_bytes, Body},
+        http::{Request, StatusCode},
+        Router,
+    };
+    use rs_data_api::{
+        admin::run_command::AllowedCommands,
+        app::{self, AppState},
+        config::MongoConfig,
+        cursors::CursorRegistry,
+        data_sources::DataSources,
+        mdb,
+    };
+    use serde_json::Value;
+    use std::collections::HashMap;
+    use tower::ServiceExt;
+
+    use crate::helpers::{build_app, get_config};
+
+    async fn get_readyz(app_router: Router) -> (StatusCode, Value) {
+        let request = Request::builder()
+            .uri("/readyz")
+            .body(Body::empty())
+            .unwrap();
+        let response = app_router.oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn readyz() {
+        let (status, body) = get_readyz(build_app().await).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["status"], "ready");
+
+        for data_source in body["dataSources"].as_object().unwrap().values() {
+            assert_eq!(data_source["status"], "ok");
+            assert_eq!(data_source["selectable"], true);
+            assert_ne!(data_source["topologyType"], "Unknown");
+            assert!(data_source["latencyMs"].as_f64().unwrap() >= 0.0);
+        }
+    }
+
+    #[tokio::test]
+    async fn readyz_untracked_topology() {
+        let config = get_config();
+        let client = mdb::get_client(&config.data_sources[&config.default_data_source])
+            .await
+            .unwrap();
+        let state = AppState::new(
+            DataSources::new(
+                HashMap::from([("default".to_string(), client)]),
+                "default".into(),
+            ),
+            CursorRegistry::default(),
+            AllowedCommands::new(["ping"]),
+        );
+
+        let (status, body) = get_readyz(app::router(state)).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["dataSources"]["default"]["status"], "ok");
+        assert_eq!(body["dataSources"]["default"]["selectable"], true);
+    }
+
+    #[tokio::test]
+    async fn readyz_error() {
+        let client = mdb::get_client(&MongoConfig {
+            uri: "mongodb://127.0.0.1:1".into(),
+            server_selection_timeout_ms: Some(100),
+            ..MongoConfig::default()
+        })
+        .await
+        .unwrap();
+        let state = AppState::new(
+            DataSources::new(
+                HashMap::from([("unreachable".to_string(), client)]),
+                "unreachable".into(),
+            ),
+            CursorRegistry::default(),
+            AllowedCommands::new(["ping"]),
+        );
+
+        let (status, body) = get_readyz(app::router(state)).await;
+
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["status"], "unavailable");
+
+        let data_source = &body["dataSources"]["unreachable"];
+
+        assert_eq!(data_source["status"], "error");
+        assert_eq!(data_source["selectable"], false);
+        assert_eq!(data_source["topologyType"], "Unknown");
+        assert!(data_source["message"].is_string());
+    }
+}
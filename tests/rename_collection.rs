@@ -0,0 +1,92 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::{
+        bson::{doc, Document},
+        error::CommandError,
+    };
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{
+        drop_database, get_db_and_collection, get_struct_from_doc, one_shot_document,
+    };
+
+    #[derive(Serialize, Deserialize)]
+    struct RenameCollectionBody {
+        pub db: String,
+        pub collection: String,
+        pub to: String,
+        pub options: Option<Document>,
+    }
+
+    #[tokio::test]
+    async fn rename_collection() {
+        let (db, collection) = get_db_and_collection().await;
+
+        collection.insert_one(doc! {"name": "john"}).await.unwrap();
+
+        let body = RenameCollectionBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            to: "users".into(),
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/renameCollection", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc, doc! {});
+        assert_eq!(db.list_collection_names().await.unwrap(), vec!["users"]);
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn rename_collection_with_options() {
+        let (db, collection) = get_db_and_collection().await;
+
+        collection.insert_one(doc! {"name": "john"}).await.unwrap();
+        db.create_collection("users").await.unwrap();
+
+        let body = RenameCollectionBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            to: "users".into(),
+            options: Some(doc! {"dropTarget": true}),
+        };
+
+        let (parts, _) = one_shot_document("/renameCollection", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(db.list_collection_names().await.unwrap(), vec!["users"]);
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn rename_collection_error() {
+        let (db, collection) = get_db_and_collection().await;
+
+        collection.insert_one(doc! {"name": "john"}).await.unwrap();
+        db.create_collection("users").await.unwrap();
+
+        let body = RenameCollectionBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            to: "users".into(),
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/renameCollection", body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+
+        let error = get_struct_from_doc::<CommandError>(doc);
+
+        assert_eq!(error.code_name, "NamespaceExists");
+
+        drop_database(&db).await;
+    }
+}
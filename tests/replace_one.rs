@@ -0,0 +1,99 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::bson::{doc, oid::ObjectId, Document};
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{drop_database, get_db_and_collection, one_shot_document};
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct ReplaceOneBody {
+        pub db: String,
+        pub collection: String,
+        pub filter: Document,
+        pub replacement: Document,
+        pub options: Option<Document>,
+    }
+
+    #[tokio::test]
+    async fn replace_one() {
+        let (db, collection) = get_db_and_collection().await;
+        let user = doc! { "_id": ObjectId::new(), "name": "john", "age": 30 };
+        let replacement = doc! { "name": "john", "email": "john@example.com" };
+
+        collection.insert_one(&user).await.unwrap();
+
+        let body = ReplaceOneBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            filter: doc! {"_id": user.get("_id").unwrap()},
+            replacement: replacement.clone(),
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/replaceOne", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc.get_i64("matchedCount").unwrap(), 1);
+        assert_eq!(doc.get_i64("modifiedCount").unwrap(), 1);
+
+        let stored = collection
+            .find_one(doc! {"_id": user.get("_id").unwrap()})
+            .projection(doc! {"_id": 0})
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(stored, replacement);
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn replace_one_upsert() {
+        let (db, collection) = get_db_and_collection().await;
+        let user = doc! { "_id": ObjectId::new(), "name": "john", "age": 30 };
+
+        let body = ReplaceOneBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            filter: doc! {"_id": user.get("_id").unwrap()},
+            replacement: user.clone(),
+            options: Some(doc! {"upsert": true}),
+        };
+
+        let (parts, doc) = one_shot_document("/replaceOne", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc.get_i64("matchedCount").unwrap(), 0);
+        assert_eq!(doc.get_i64("modifiedCount").unwrap(), 0);
+        assert_eq!(doc.get("upsertedId"), user.get("_id"));
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn replace_one_error() {
+        let (db, collection) = get_db_and_collection().await;
+
+        let body = ReplaceOneBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            filter: doc! {"name": "john"},
+            replacement: doc! {"name": "john", "$set": {"age": 35}},
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/replaceOne", body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+        assert!(doc
+            .get_str("message")
+            .unwrap()
+            .contains("must not contain update operators"));
+
+        drop_database(&db).await;
+    }
+}
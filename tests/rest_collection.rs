@@ -0,0 +1,110 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        http::{header, response::Parts, Method, Request, StatusCode},
+    };
+    use mongodb::bson::{doc, oid::ObjectId, Bson, Document};
+    use tower::ServiceExt;
+
+    use crate::helpers::{
+        build_app, drop_database, get_array_from_body, get_body_ejson_from_struct,
+        get_db_and_collection, get_document_from_body,
+    };
+
+    async fn request(method: Method, uri: &str, body: Body) -> (Parts, Body) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(header::CONTENT_TYPE, "application/ejson")
+            .body(body)
+            .unwrap();
+
+        build_app()
+            .await
+            .oneshot(request)
+            .await
+            .unwrap()
+            .into_parts()
+    }
+
+    #[tokio::test]
+    async fn rest_collection() {
+        let (db, collection) = get_db_and_collection().await;
+        let id = ObjectId::new();
+        let uri = format!("/db/{}/{}", db.name(), collection.name());
+
+        let (parts, body) = request(
+            Method::POST,
+            &uri,
+            get_body_ejson_from_struct(doc! {"_id": id, "name": "john"}),
+        )
+        .await;
+        let doc = get_document_from_body(body).await;
+
+        assert_eq!(parts.status, StatusCode::CREATED);
+        assert_eq!(
+            parts.headers[header::LOCATION],
+            format!("{uri}/{}", id.to_hex()).as_str()
+        );
+        assert_eq!(doc.get_object_id("insertedId").unwrap(), id);
+
+        let (parts, body) = request(Method::GET, &uri, Body::empty()).await;
+        let documents = get_array_from_body(body).await;
+
+        assert_eq!(parts.status, StatusCode::OK);
+        assert_eq!(
+            documents,
+            vec![Bson::Document(doc! {"_id": id, "name": "john"})]
+        );
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn rest_collection_with_options() {
+        let (db, collection) = get_db_and_collection().await;
+
+        collection
+            .insert_many(vec![
+                doc! {"name": "john", "age": 30},
+                doc! {"name": "jim", "age": 40},
+                doc! {"name": "jack", "age": 50},
+            ])
+            .await
+            .unwrap();
+
+        let uri = format!(
+            "/db/{}/{}?filter=%7B%22age%22%3A%7B%22%24gte%22%3A40%7D%7D&projection=%7B%22_id%22%3A0%2C%22name%22%3A1%7D&sort=%7B%22age%22%3A-1%7D&limit=1",
+            db.name(),
+            collection.name()
+        );
+
+        let (parts, body) = request(Method::GET, &uri, Body::empty()).await;
+        let documents = get_array_from_body(body).await;
+
+        assert_eq!(parts.status, StatusCode::OK);
+        assert_eq!(documents, vec![Bson::Document(doc! {"name": "jack"})]);
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn rest_collection_error() {
+        let (db, collection) = get_db_and_collection().await;
+        let uri = format!("/db/{}/{}?filter=%5B%5D", db.name(), collection.name());
+
+        let (parts, body) = request(Method::GET, &uri, Body::empty()).await;
+        let doc: Document = get_document_from_body(body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+        assert_eq!(
+            doc.get_str("message").unwrap(),
+            "Query parameter filter must be an EJSON document"
+        );
+
+        drop_database(&db).await;
+    }
+}
@@ -0,0 +1,163 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        http::{header, response::Parts, Method, Request, StatusCode},
+    };
+    use mongodb::bson::{doc, oid::ObjectId};
+    use tower::ServiceExt;
+
+    use crate::helpers::{
+        build_app, drop_database, get_body_ejson_from_struct, get_db_and_collection,
+        get_document_from_body,
+    };
+
+    async fn request(method: Method, uri: &str, body: Body) -> (Parts, Body) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(header::CONTENT_TYPE, "application/ejson")
+            .body(body)
+            .unwrap();
+
+        build_app()
+            .await
+            .oneshot(request)
+            .await
+            .unwrap()
+            .into_parts()
+    }
+
+    #[tokio::test]
+    async fn rest_document() {
+        let (db, collection) = get_db_and_collection().await;
+        let id = ObjectId::new();
+        let uri = format!("/db/{}/{}/{}", db.name(), collection.name(), id.to_hex());
+
+        collection
+            .insert_one(doc! {"_id": id, "name": "john", "age": 30})
+            .await
+            .unwrap();
+
+        let (parts, body) = request(Method::GET, &uri, Body::empty()).await;
+        let doc = get_document_from_body(body).await;
+
+        assert_eq!(parts.status, StatusCode::OK);
+        assert_eq!(doc, doc! {"_id": id, "name": "john", "age": 30});
+
+        let (parts, body) = request(
+            Method::PATCH,
+            &uri,
+            get_body_ejson_from_struct(doc! {"$set": {"age": 31}}),
+        )
+        .await;
+        let doc = get_document_from_body(body).await;
+
+        assert_eq!(parts.status, StatusCode::OK);
+        assert_eq!(doc.get_i64("modifiedCount").unwrap(), 1);
+
+        let (parts, _) = request(Method::DELETE, &uri, Body::empty()).await;
+
+        assert_eq!(parts.status, StatusCode::OK);
+        assert_eq!(collection.count_documents(doc! {}).await.unwrap(), 0);
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn rest_document_with_options() {
+        let (db, collection) = get_db_and_collection().await;
+        let uri = format!("/db/{}/{}/user-1", db.name(), collection.name());
+
+        let (parts, body) = request(
+            Method::PUT,
+            &uri,
+            get_body_ejson_from_struct(doc! {"name": "jim"}),
+        )
+        .await;
+        let doc = get_document_from_body(body).await;
+
+        assert_eq!(parts.status, StatusCode::CREATED);
+        assert_eq!(doc.get_str("upsertedId").unwrap(), "user-1");
+
+        let (parts, body) = request(
+            Method::PUT,
+            &uri,
+            get_body_ejson_from_struct(doc! {"name": "jack"}),
+        )
+        .await;
+        let doc = get_document_from_body(body).await;
+
+        assert_eq!(parts.status, StatusCode::OK);
+        assert_eq!(doc.get_i64("modifiedCount").unwrap(), 1);
+        assert!(doc.get("upsertedId").is_none());
+
+        collection
+            .insert_one(doc! {"_id": "123", "name": "joe"})
+            .await
+            .unwrap();
+
+        let uri = format!("/db/{}/{}/123", db.name(), collection.name());
+        let (parts, body) = request(Method::GET, &uri, Body::empty()).await;
+        let doc = get_document_from_body(body).await;
+
+        assert_eq!(parts.status, StatusCode::OK);
+        assert_eq!(doc, doc! {"_id": "123", "name": "joe"});
+
+        let uri = format!(
+            "/db/{}/{}/%7B%22%24numberInt%22%3A%227%22%7D",
+            db.name(),
+            collection.name()
+        );
+
+        collection
+            .insert_one(doc! {"_id": 7, "name": "jack"})
+            .await
+            .unwrap();
+
+        let (parts, body) = request(Method::GET, &uri, Body::empty()).await;
+        let doc = get_document_from_body(body).await;
+
+        assert_eq!(parts.status, StatusCode::OK);
+        assert_eq!(doc, doc! {"_id": 7, "name": "jack"});
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn rest_document_error() {
+        let (db, collection) = get_db_and_collection().await;
+        let uri = format!(
+            "/db/{}/{}/{}",
+            db.name(),
+            collection.name(),
+            ObjectId::new().to_hex()
+        );
+
+        for method in [Method::GET, Method::DELETE] {
+            let (parts, body) = request(method, &uri, Body::empty()).await;
+            let doc = get_document_from_body(body).await;
+
+            assert_eq!(parts.status, StatusCode::NOT_FOUND);
+            assert_eq!(doc.get_str("message").unwrap(), "Document not found");
+        }
+
+        let (parts, body) = request(
+            Method::PUT,
+            &uri,
+            get_body_ejson_from_struct(doc! {"$set": {"name": "jim"}}),
+        )
+        .await;
+        let doc = get_document_from_body(body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+        assert_eq!(
+            doc.get_str("message").unwrap(),
+            "Replacement document must not contain update operators: $set"
+        );
+
+        drop_database(&db).await;
+    }
+}
@@ -0,0 +1,89 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::{
+        bson::{doc, Document},
+        error::CommandError,
+    };
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{
+        drop_database, get_db_and_collection, get_struct_from_doc, one_shot_document,
+    };
+
+    #[derive(Serialize, Deserialize)]
+    struct RunCommandBody {
+        pub db: String,
+        pub command: Document,
+    }
+
+    #[tokio::test]
+    async fn run_command() {
+        let (db, collection) = get_db_and_collection().await;
+
+        collection.insert_one(doc! {"name": "john"}).await.unwrap();
+
+        let body = RunCommandBody {
+            db: db.name().into(),
+            command: doc! {"dbStats": 1},
+        };
+
+        let (parts, doc) = one_shot_document("/runCommand", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc.get_str("db").unwrap(), db.name());
+        assert_eq!(doc.get_f64("ok").unwrap(), 1.0);
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn run_command_not_allowed() {
+        let (db, collection) = get_db_and_collection().await;
+
+        collection.insert_one(doc! {"name": "john"}).await.unwrap();
+
+        let body = RunCommandBody {
+            db: db.name().into(),
+            command: doc! {"dropDatabase": 1},
+        };
+
+        let (parts, doc) = one_shot_document("/runCommand", body).await;
+
+        assert_eq!(parts.status, StatusCode::FORBIDDEN);
+        assert_eq!(
+            doc.get_str("message").unwrap(),
+            "Command not allowed: dropDatabase"
+        );
+        assert!(db
+            .client()
+            .list_database_names()
+            .await
+            .unwrap()
+            .contains(&db.name().to_string()));
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn run_command_error() {
+        let (db, collection) = get_db_and_collection().await;
+
+        let body = RunCommandBody {
+            db: db.name().into(),
+            command: doc! {"collMod": collection.name(), "validationLevel": "unknown"},
+        };
+
+        let (parts, doc) = one_shot_document("/runCommand", body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+
+        let error = get_struct_from_doc::<CommandError>(doc);
+
+        assert!(!error.message.is_empty());
+
+        drop_database(&db).await;
+    }
+}
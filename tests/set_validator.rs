@@ -0,0 +1,138 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::{
+        bson::{doc, Document},
+        error::WriteFailure,
+    };
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{
+        drop_database, get_db_and_collection, get_struct_from_doc, one_shot_document,
+    };
+
+    #[derive(Debug, Deserialize, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct SetValidatorBody {
+        db: String,
+        collection: String,
+        validator: Option<Document>,
+        validation_level: Option<String>,
+        validation_action: Option<String>,
+    }
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct InsertOneBody {
+        db: String,
+        collection: String,
+        document: Document,
+    }
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct FindOneAndUpdateBody {
+        db: String,
+        collection: String,
+        filter: Document,
+        update: Document,
+        options: Option<Document>,
+    }
+
+    #[tokio::test]
+    async fn set_validator() {
+        let (db, collection) = get_db_and_collection().await;
+
+        db.create_collection(collection.name()).await.unwrap();
+
+        let body = SetValidatorBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            validator: Some(doc! {"$jsonSchema": {"required": ["name"]}}),
+            validation_level: None,
+            validation_action: None,
+        };
+
+        let (parts, doc) = one_shot_document("/setValidator", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+        assert_eq!(doc, doc! {});
+
+        let body = InsertOneBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            document: doc! {"age": 30},
+        };
+
+        let (parts, doc) = one_shot_document("/insertOne", body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+
+        if let WriteFailure::WriteError(error) = get_struct_from_doc::<WriteFailure>(doc) {
+            assert_eq!(error.code, 121);
+            assert!(error.details.unwrap().contains_key("details"));
+        } else {
+            panic!("expected a write error");
+        }
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn set_validator_with_options() {
+        let (db, collection) = get_db_and_collection().await;
+
+        db.create_collection(collection.name()).await.unwrap();
+
+        let body = SetValidatorBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            validator: Some(doc! {"$jsonSchema": {"required": ["name"]}}),
+            validation_level: Some("strict".into()),
+            validation_action: Some("error".into()),
+        };
+
+        let (parts, _) = one_shot_document("/setValidator", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+
+        let body = FindOneAndUpdateBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            filter: doc! {"age": 30},
+            update: doc! {"$set": {"age": 31}},
+            options: Some(doc! {"upsert": true}),
+        };
+
+        let (parts, doc) = one_shot_document("/findOneAndUpdate", body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+        assert_eq!(doc.get_i32("code").unwrap(), 121);
+        assert!(doc.get_document("errInfo").unwrap().contains_key("details"));
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn set_validator_error() {
+        let (db, collection) = get_db_and_collection().await;
+
+        let body = SetValidatorBody {
+            db: db.name().into(),
+            collection: collection.name().into(),
+            validator: None,
+            validation_level: None,
+            validation_action: None,
+        };
+
+        let (parts, doc) = one_shot_document("/setValidator", body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+        assert_eq!(
+            doc.get_str("message").unwrap(),
+            "At least one of validator, validationLevel or validationAction is required"
+        );
+
+        drop_database(&db).await;
+    }
+}
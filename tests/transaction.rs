@@ -0,0 +1,113 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mongodb::{
+        bson::{doc, oid::ObjectId, Document},
+        error::WriteFailure,
+    };
+    use serde::{Deserialize, Serialize};
+
+    use crate::helpers::{
+        drop_database, get_db_and_collection, get_struct_from_doc, one_shot_document,
+    };
+
+    #[derive(Serialize, Deserialize)]
+    struct TransactionBody {
+        pub operations: Vec<Document>,
+        pub options: Option<Document>,
+    }
+
+    #[tokio::test]
+    async fn transaction() {
+        let (db, accounts) = get_db_and_collection().await;
+        let transfers = db.collection::<Document>("transfers");
+        let account = doc! { "_id": ObjectId::new(), "name": "john", "balance": 100 };
+        let transfer = doc! { "_id": ObjectId::new(), "from": "john", "amount": 30 };
+
+        accounts.insert_one(&account).await.unwrap();
+        db.create_collection(transfers.name()).await.unwrap();
+
+        let body = TransactionBody {
+            operations: vec![
+                doc! {"updateOne": {
+                    "db": db.name(),
+                    "collection": accounts.name(),
+                    "query": {"name": "john"},
+                    "update": {"$inc": {"balance": -30}},
+                }},
+                doc! {"insertOne": {
+                    "db": db.name(),
+                    "collection": transfers.name(),
+                    "document": &transfer,
+                }},
+                doc! {"findOne": {
+                    "db": db.name(),
+                    "collection": accounts.name(),
+                    "filter": {"name": "john"},
+                }},
+            ],
+            options: Some(doc! {
+                "readConcern": {"level": "snapshot"},
+                "writeConcern": {"w": "majority"},
+            }),
+        };
+
+        let (parts, doc) = one_shot_document("/transaction", body).await;
+
+        assert_eq!(parts.status, StatusCode::ACCEPTED);
+
+        let results = doc.get_array("results").unwrap();
+        let update = results.first().unwrap().as_document().unwrap();
+        let insert = results.get(1).unwrap().as_document().unwrap();
+        let find = results.get(2).unwrap().as_document().unwrap();
+
+        assert_eq!(update.get_i64("modifiedCount").unwrap(), 1);
+        assert_eq!(insert.get("insertedId"), transfer.get("_id"));
+        assert_eq!(find.get_i32("balance").unwrap(), 70);
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn transaction_error() {
+        let (db, accounts) = get_db_and_collection().await;
+        let account = doc! { "_id": ObjectId::new(), "name": "john", "balance": 100 };
+
+        accounts.insert_one(&account).await.unwrap();
+
+        let body = TransactionBody {
+            operations: vec![
+                doc! {"updateOne": {
+                    "db": db.name(),
+                    "collection": accounts.name(),
+                    "query": {"name": "john"},
+                    "update": {"$inc": {"balance": -30}},
+                }},
+                doc! {"insertOne": {
+                    "db": db.name(),
+                    "collection": accounts.name(),
+                    "document": &account,
+                }},
+            ],
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/transaction", body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+
+        if let WriteFailure::WriteError(error) = get_struct_from_doc::<WriteFailure>(doc) {
+            assert!(error.message.contains("E11000 duplicate key error"));
+        } else {
+            panic!("wrong")
+        }
+
+        let stored = accounts.find_one(doc! {}).await.unwrap().unwrap();
+
+        assert_eq!(stored.get_i32("balance").unwrap(), 100);
+
+        drop_database(&db).await;
+    }
+}
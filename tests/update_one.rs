@@ -9,7 +9,9 @@ mod tests {
     };
     use serde::{Deserialize, Serialize};
 
-    use crate::helpers::{get_db_and_collection, get_struct_from_doc, one_shot_document};
+    use crate::helpers::{
+        drop_database, get_db_and_collection, get_struct_from_doc, one_shot_document,
+    };
 
     #[derive(Debug, Serialize, Deserialize)]
     struct UpdateOneBody {
@@ -43,7 +45,7 @@ mod tests {
         assert_eq!(doc.get_i64("matchedCount").unwrap(), 1);
         assert_eq!(doc.get_i64("modifiedCount").unwrap(), 1);
 
-        db.drop().await.unwrap();
+        drop_database(&db).await;
     }
 
     #[tokio::test]
@@ -69,7 +71,7 @@ mod tests {
         assert_eq!(doc.get_i64("modifiedCount").unwrap(), 0);
         assert_eq!(doc.get("upsertedId"), user.get("_id"));
 
-        db.drop().await.unwrap();
+        drop_database(&db).await;
     }
 
     #[tokio::test]
@@ -98,6 +100,6 @@ mod tests {
         assert_eq!(parts.status, StatusCode::BAD_REQUEST);
         assert!(error_message.contains("Unknown modifier"));
 
-        db.drop().await.unwrap();
+        drop_database(&db).await;
     }
 }
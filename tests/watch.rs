@@ -0,0 +1,125 @@
+mod helpers;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::{header, StatusCode};
+    use futures::stream::StreamExt;
+    use mongodb::bson::{doc, oid::ObjectId, Bson, Document};
+    use serde::{Deserialize, Serialize};
+    use serde_json::Value;
+
+    use rs_data_api::app::{self, AppState};
+    use std::time::Duration;
+
+    use crate::helpers::{
+        drop_database, get_config, get_db_and_collection, one_shot, one_shot_document,
+        one_shot_with,
+    };
+
+    #[derive(Serialize, Deserialize)]
+    struct WatchBody {
+        pub db: Option<String>,
+        pub collection: Option<String>,
+        pub pipeline: Option<Vec<Document>>,
+        pub options: Option<Document>,
+    }
+
+    #[tokio::test]
+    async fn watch() {
+        let (db, collection) = get_db_and_collection().await;
+        let user = doc! { "_id": ObjectId::new(), "name": "john", "age": 30 };
+
+        db.create_collection(collection.name()).await.unwrap();
+
+        let body = WatchBody {
+            db: Some(db.name().into()),
+            collection: Some(collection.name().into()),
+            pipeline: Some(vec![doc! {"$match": {"operationType": "insert"}}]),
+            options: Some(doc! {"fullDocument": "updateLookup"}),
+        };
+
+        let (parts, body) = one_shot("/watch", body).await;
+
+        assert_eq!(parts.status, StatusCode::OK);
+        assert_eq!(
+            parts.headers.get(header::CONTENT_TYPE).unwrap(),
+            "text/event-stream"
+        );
+
+        collection.insert_one(&user).await.unwrap();
+
+        let mut chunks = body.into_data_stream();
+        let mut received = String::new();
+
+        while !received.contains("\n\n") {
+            let chunk = chunks.next().await.unwrap().unwrap();
+
+            received.push_str(std::str::from_utf8(&chunk).unwrap());
+        }
+
+        let data = received
+            .lines()
+            .find_map(|line| line.strip_prefix("data: "))
+            .unwrap();
+        let event_json: Value = serde_json::from_str(data).unwrap();
+        let event_bson: Bson = event_json.try_into().unwrap();
+        let event = event_bson.as_document().unwrap();
+
+        assert_eq!(event.get_str("operationType").unwrap(), "insert");
+        assert_eq!(event.get_document("fullDocument").unwrap(), &user);
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn watch_error() {
+        let (db, collection) = get_db_and_collection().await;
+
+        let body = WatchBody {
+            db: None,
+            collection: Some(collection.name().into()),
+            pipeline: None,
+            options: None,
+        };
+
+        let (parts, doc) = one_shot_document("/watch", body).await;
+
+        assert_eq!(parts.status, StatusCode::BAD_REQUEST);
+        assert_eq!(doc.get_str("message").unwrap(), "Collection requires a db");
+
+        drop_database(&db).await;
+    }
+
+    #[tokio::test]
+    async fn watch_closes_on_shutdown() {
+        let (db, collection) = get_db_and_collection().await;
+        let state = AppState::connect(&get_config()).await.unwrap();
+        let app_router = app::router(state.clone());
+
+        db.create_collection(collection.name()).await.unwrap();
+
+        let body = WatchBody {
+            db: Some(db.name().into()),
+            collection: Some(collection.name().into()),
+            pipeline: None,
+            options: None,
+        };
+
+        let (parts, body) = one_shot_with(&app_router, "/watch", body).await;
+
+        assert_eq!(parts.status, StatusCode::OK);
+
+        state.close_streams();
+
+        let mut chunks = body.into_data_stream();
+        let ended = tokio::time::timeout(Duration::from_secs(5), async {
+            while chunks.next().await.is_some() {}
+        })
+        .await;
+
+        assert!(ended.is_ok());
+
+        state.shutdown().await;
+        drop_database(&db).await;
+    }
+}